@@ -0,0 +1,131 @@
+//! Throughput of the leap second lookup on the conversion hot path, for single and batch conversions.
+//!
+//! The lookup alone is timed against the linear scan it replaced, then the conversions using it. Run with
+//! `cargo bench --bench leap_lookup`. Kept dependency-free, timing with `std::time::Instant`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use chrono_gpst::{from_gpst, from_gpst_batch, gpst_batch, GpstLike, LEAP_SECOND_TABLE};
+
+const EPOCHS: i64 = 1_000_000;
+const ROUNDS: u32 = 5;
+
+/// Best time of a few rounds, to damp scheduler noise.
+fn best_of(mut run: impl FnMut()) -> Duration {
+    (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+/// The linear scan `num_leaps` used before the binary search, as a baseline.
+fn linear_num_leaps(leap_nanoseconds: &[i64], gps_nanoseconds: i64) -> i64 {
+    let mut count = 0;
+    for leap_nanoseconds in leap_nanoseconds {
+        if *leap_nanoseconds < gps_nanoseconds {
+            count += 1_000_000_000;
+        }
+    }
+    count
+}
+
+/// The binary search `num_leaps` now uses, mirroring the crate's private `count_leaps`.
+fn binary_num_leaps(leap_nanoseconds: &[i64], gps_nanoseconds: i64) -> i64 {
+    let (mut low, mut high) = (0, leap_nanoseconds.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if leap_nanoseconds[middle] < gps_nanoseconds {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low as i64 * 1_000_000_000
+}
+
+fn report(name: &str, elapsed: Duration) {
+    println!(
+        "{name:<24} {:>8.1} ns/epoch",
+        elapsed.as_nanos() as f64 / EPOCHS as f64
+    );
+}
+
+fn main() {
+    // One epoch a second from 2016-12-31, straddling the latest leap second as an observation file would.
+    let start = Utc.with_ymd_and_hms(2016, 12, 31, 12, 0, 0).unwrap();
+    let epochs: Vec<DateTime<Utc>> = (0..EPOCHS)
+        .map(|step| start + TimeDelta::seconds(step))
+        .collect();
+    let pairs: Vec<(i64, f64)> = gpst_batch(&epochs, true)
+        .into_iter()
+        .map(|gpst| {
+            let gpst = gpst.unwrap();
+            (gpst.week, gpst.week_seconds)
+        })
+        .collect();
+    let leap_nanoseconds: Vec<i64> = LEAP_SECOND_TABLE
+        .iter()
+        .map(|leap_second| leap_second.gps_seconds * 1_000_000_000)
+        .collect();
+    let gps_nanoseconds: Vec<i64> = pairs
+        .iter()
+        .map(|&(week, week_seconds)| week * 604_800_000_000_000 + (week_seconds * 1e9) as i64)
+        .collect();
+    assert!(gps_nanoseconds.iter().all(|&nanoseconds| {
+        linear_num_leaps(&leap_nanoseconds, nanoseconds)
+            == binary_num_leaps(&leap_nanoseconds, nanoseconds)
+    }));
+
+    let linear = best_of(|| {
+        for &nanoseconds in &gps_nanoseconds {
+            black_box(linear_num_leaps(&leap_nanoseconds, black_box(nanoseconds)));
+        }
+    });
+    let binary = best_of(|| {
+        for &nanoseconds in &gps_nanoseconds {
+            black_box(binary_num_leaps(&leap_nanoseconds, black_box(nanoseconds)));
+        }
+    });
+    report("lookup linear baseline", linear);
+    report("lookup binary search", binary);
+    println!(
+        "{:<24} {:>8.2}x",
+        "lookup speedup",
+        linear.as_secs_f64() / binary.as_secs_f64()
+    );
+
+    report(
+        "gpst",
+        best_of(|| {
+            for epoch in &epochs {
+                black_box(black_box(epoch).gpst(true).unwrap());
+            }
+        }),
+    );
+    report(
+        "from_gpst",
+        best_of(|| {
+            for &(week, week_seconds) in &pairs {
+                black_box(from_gpst(black_box(week), black_box(week_seconds), true).unwrap());
+            }
+        }),
+    );
+    report(
+        "gpst_batch",
+        best_of(|| {
+            black_box(gpst_batch(black_box(&epochs), true));
+        }),
+    );
+    report(
+        "from_gpst_batch",
+        best_of(|| {
+            black_box(from_gpst_batch(black_box(&pairs), true));
+        }),
+    );
+}
@@ -0,0 +1,76 @@
+//! With the `verify-leap-table` feature, parse the vendored `data/leap-seconds.list` into a table of leap seconds
+//! since GPS Epoch, which the library asserts its builtin table against at compile time. With the `ffi` feature, also
+//! generate the C header.
+
+use std::{env, fs, path::Path};
+
+const LEAP_SECONDS_LIST: &str = "data/leap-seconds.list";
+/// NTP timestamp (seconds since 1900-01-01) of GPS Epoch.
+const NTP_GPS_EPOCH: i64 = 2524953600;
+/// TAI - UTC at GPS Epoch.
+const TAI_UTC_GPS_EPOCH: i64 = 19;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    #[cfg(feature = "ffi")]
+    generate_header();
+    if env::var_os("CARGO_FEATURE_VERIFY_LEAP_TABLE").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-changed={LEAP_SECONDS_LIST}");
+
+    let list = fs::read_to_string(LEAP_SECONDS_LIST)
+        .unwrap_or_else(|error| panic!("Could not read {LEAP_SECONDS_LIST}: {error}"));
+    let mut leap_seconds = Vec::new();
+    for line in list.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let mut next_field = |name: &str| -> i64 {
+            fields
+                .next()
+                .and_then(|field| field.parse().ok())
+                .unwrap_or_else(|| panic!("Invalid {name} in {LEAP_SECONDS_LIST}: {line}"))
+        };
+        let ntp_seconds = next_field("NTP timestamp");
+        let tai_utc = next_field("TAI - UTC");
+        if tai_utc <= TAI_UTC_GPS_EPOCH {
+            continue;
+        }
+        // The builtin table holds the GPS time of each inserted second, which includes the leaps before it.
+        let utc_seconds = ntp_seconds - NTP_GPS_EPOCH;
+        leap_seconds.push(utc_seconds + tai_utc - TAI_UTC_GPS_EPOCH - 1);
+    }
+
+    let table = leap_seconds
+        .iter()
+        .map(i64::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("leap_seconds_list.rs"),
+        format!("[{table}]"),
+    )
+    .expect("Could not write parsed leap seconds");
+}
+
+/// With the `ffi` feature, generate the C header for `src/ffi.rs`, which the test suite compares against the
+/// checked-in `include/chrono_gpst.h`.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let config = cbindgen::Config::from_file(Path::new(&crate_dir).join("cbindgen.toml"))
+        .expect("Could not read cbindgen.toml");
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(Path::new(&crate_dir).join("src/ffi.rs"))
+        .generate()
+        .expect("Could not generate the C header")
+        .write_to_file(Path::new(&out_dir).join("chrono_gpst.h"));
+}
@@ -0,0 +1,93 @@
+#ifndef CHRONO_GPST_H
+#define CHRONO_GPST_H
+
+/* Generated by cbindgen from src/ffi.rs with the `ffi` feature. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a C interface call.
+ */
+typedef enum GpstStatus {
+  /**
+   * The conversion succeeded.
+   */
+  GPST_STATUS_OK = 0,
+  /**
+   * An out pointer was null.
+   */
+  GPST_STATUS_NULL_POINTER = 1,
+  /**
+   * The instant is earlier than GPS Epoch.
+   */
+  GPST_STATUS_BEFORE_GPS_EPOCH = 2,
+  /**
+   * The instant is outside of the supported date range.
+   */
+  GPST_STATUS_OUT_OF_RANGE = 3,
+  /**
+   * The input is NaN, infinite, or week seconds are outside of the week.
+   */
+  GPST_STATUS_INVALID_INPUT = 4,
+} GpstStatus;
+
+/**
+ * GPS week and seconds of week.
+ */
+typedef struct GpstTime {
+  /**
+   * Weeks since GPS Epoch.
+   */
+  int64_t week;
+  /**
+   * Seconds of the week, from 0 to 604800 exclusive.
+   */
+  double week_seconds;
+} GpstTime;
+
+/**
+ * Convert a Unix timestamp (UTC) to GPS week and seconds of week. Optionally, adjust for leap seconds.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writing a [`GpstTime`].
+ */
+enum GpstStatus gpst_from_unix(int64_t unix_seconds,
+                               uint32_t nanoseconds,
+                               bool leap_seconds,
+                               struct GpstTime *out);
+
+/**
+ * Convert GPS week and seconds of week to a Unix timestamp (UTC), in whole seconds and nanoseconds. Optionally,
+ * adjust for leap seconds.
+ *
+ * # Safety
+ *
+ * `unix_seconds` and `nanoseconds` must each be null or valid for writing.
+ */
+enum GpstStatus gpst_to_unix(struct GpstTime time,
+                             bool leap_seconds,
+                             int64_t *unix_seconds,
+                             uint32_t *nanoseconds);
+
+/**
+ * Build a validated [`GpstTime`] from a GPS week and time of week, as `Gpst::new` does.
+ *
+ * # Safety
+ *
+ * `out` must be null or valid for writing a [`GpstTime`].
+ */
+enum GpstStatus gpst_from_week_tow(int64_t week, double tow, struct GpstTime *out);
+
+/**
+ * A static, NUL-terminated description of a status.
+ *
+ * Takes the raw status value, since C can pass any `int` as a `GpstStatus`; unknown values get a generic message.
+ */
+const char *gpst_status_message(int32_t status);
+
+#endif  /* CHRONO_GPST_H */
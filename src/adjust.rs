@@ -0,0 +1,255 @@
+//! Chainable adjusters, e.g. `gpst.with_tow(0.0).with_week_delta(1).truncate_to_seconds()`.
+//!
+//! Each adjuster returns a normalized value, with week seconds in `0..604800` and `seconds` kept consistent.
+
+use chrono::TimeDelta;
+
+use crate::{checked_nanoseconds, Gpst, GpstError, TimeInput, MAX_WIDE_NANOSECONDS, TO_NANO_FLOAT};
+
+impl Gpst {
+    /// Replace the week seconds, carrying values outside of the week into the week number.
+    pub fn with_tow(self, week_seconds: f64) -> Gpst {
+        Gpst::normalized(self.week, week_seconds).derived_from(&self)
+    }
+
+    /// Replace the week number, keeping the week seconds.
+    pub fn with_week(self, week: i64) -> Gpst {
+        Gpst::normalized(week, self.week_seconds).derived_from(&self)
+    }
+
+    /// Move by a number of whole weeks.
+    pub fn with_week_delta(self, weeks: i64) -> Gpst {
+        Gpst::normalized(self.week + weeks, self.week_seconds).derived_from(&self)
+    }
+
+    /// Move by a number of seconds, carrying into the week number.
+    pub fn with_seconds_delta(self, seconds: f64) -> Gpst {
+        Gpst::normalized(self.week, self.week_seconds + seconds).derived_from(&self)
+    }
+
+    /// Move by a number of seconds, failing instead of overflowing on garbage input, e.g. corrupted week numbers.
+    pub fn checked_add(self, seconds: f64) -> Result<Gpst, GpstError> {
+        let overflow = || GpstError::Overflow(TimeInput::Shifted(self, seconds));
+        let gpst = self
+            .checked_nanoseconds_since_epoch()
+            .zip(checked_nanoseconds(seconds))
+            .and_then(|(nanoseconds, delta)| {
+                Gpst::checked_from_wide_nanoseconds(nanoseconds + delta as i128)
+            })
+            .ok_or_else(overflow)?;
+        Ok(gpst.derived_from(&self))
+    }
+
+    /// Move back by a number of seconds, failing instead of overflowing on garbage input or going before GPS Epoch.
+    pub fn checked_sub(self, seconds: f64) -> Result<Gpst, GpstError> {
+        let gpst = self.checked_add(-seconds)?;
+        if gpst.week < 0 {
+            return Err(GpstError::BeforeGPSEpoch(TimeInput::Shifted(
+                self, -seconds,
+            )));
+        }
+        Ok(gpst)
+    }
+
+    /// Move by a number of seconds, clamping at GPS Epoch and at the end of the last `i64` week instead of
+    /// overflowing. NaN seconds leave the epoch unchanged.
+    pub fn saturating_add(self, seconds: f64) -> Gpst {
+        if seconds.is_nan() {
+            return self;
+        }
+        let nanoseconds = self
+            .checked_nanoseconds_since_epoch()
+            .unwrap_or(if self.week < 0 {
+                0
+            } else {
+                MAX_WIDE_NANOSECONDS
+            });
+        // `as` saturates float to integer conversions.
+        let delta = (seconds * TO_NANO_FLOAT).round() as i128;
+        let nanoseconds = nanoseconds
+            .saturating_add(delta)
+            .clamp(0, MAX_WIDE_NANOSECONDS);
+        Gpst::from_wide_nanoseconds(nanoseconds).derived_from(&self)
+    }
+
+    /// Move back by a number of seconds, clamping at GPS Epoch (week 0, 0 seconds) instead of going negative.
+    pub fn saturating_sub(self, seconds: f64) -> Gpst {
+        self.saturating_add(-seconds)
+    }
+
+    /// Move back by a number of whole weeks, clamping at GPS Epoch instead of going negative.
+    pub fn saturating_sub_weeks(self, weeks: i64) -> Gpst {
+        self.with_week_delta(-weeks).max_epoch()
+    }
+
+    /// Clamp values before GPS Epoch to GPS Epoch.
+    fn max_epoch(self) -> Gpst {
+        if self.week < 0 {
+            Gpst::normalized(0, 0.0).derived_from(&self)
+        } else {
+            self
+        }
+    }
+
+    /// Round to the nearest multiple of `interval` since GPS Epoch, with midpoints rounding up. Computed in integer
+    /// nanoseconds, so e.g. `480612.9999999` rounds to exactly `480613` on a 1 s grid.
+    pub fn round_to(self, interval: TimeDelta) -> Result<Gpst, GpstError> {
+        self.align_to(interval, |nanoseconds, interval| {
+            (nanoseconds + interval / 2).div_euclid(interval)
+        })
+    }
+
+    /// Round down to a multiple of `interval` since GPS Epoch.
+    pub fn floor_to(self, interval: TimeDelta) -> Result<Gpst, GpstError> {
+        self.align_to(interval, i128::div_euclid)
+    }
+
+    /// Round up to a multiple of `interval` since GPS Epoch.
+    pub fn ceil_to(self, interval: TimeDelta) -> Result<Gpst, GpstError> {
+        self.align_to(interval, |nanoseconds, interval| {
+            nanoseconds.div_euclid(interval) + (nanoseconds.rem_euclid(interval) != 0) as i128
+        })
+    }
+
+    /// Align to the grid index `align(nanoseconds, interval)` picks, in nanoseconds since GPS Epoch.
+    fn align_to(
+        self,
+        interval: TimeDelta,
+        align: impl Fn(i128, i128) -> i128,
+    ) -> Result<Gpst, GpstError> {
+        let interval_nanoseconds = interval
+            .num_nanoseconds()
+            .filter(|nanoseconds| *nanoseconds > 0)
+            .ok_or(GpstError::InvalidInterval(interval))?
+            as i128;
+        let index = align(self.wide_nanoseconds_since_epoch(), interval_nanoseconds);
+        let gpst = Gpst::checked_from_wide_nanoseconds(index * interval_nanoseconds).ok_or(
+            GpstError::Overflow(TimeInput::WeekSeconds(self.week, self.week_seconds)),
+        )?;
+        Ok(gpst.derived_from(&self))
+    }
+
+    /// Drop the fractional part of the week seconds.
+    pub fn truncate_to_seconds(self) -> Gpst {
+        Gpst::normalized(self.week, self.week_seconds.trunc()).derived_from(&self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Gpst;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn chain() {
+        let gpst = Gpst::normalized(1307, 480613.75);
+        assert_eq!(
+            gpst.with_tow(0.0).with_week_delta(1).truncate_to_seconds(),
+            Gpst::normalized(1308, 0.0)
+        );
+        assert_eq!(gpst.truncate_to_seconds().week_seconds, 480613.0);
+        assert_eq!(gpst.with_week(2280).seconds, 2280.0 * 604800.0 + 480613.75);
+    }
+
+    #[test]
+    fn carry() {
+        let gpst = Gpst::normalized(1307, 10.0);
+        assert_eq!(
+            gpst.with_seconds_delta(-20.0),
+            Gpst::normalized(1306, 604790.0)
+        );
+        assert_eq!(gpst.with_tow(604810.0), Gpst::normalized(1308, 10.0));
+    }
+
+    #[test]
+    fn saturating() {
+        let gpst = Gpst::normalized(1, 10.0);
+        assert_eq!(gpst.saturating_sub(20.0), Gpst::normalized(0, 604790.0));
+        assert_eq!(gpst.saturating_sub(604811.0), Gpst::normalized(0, 0.0));
+        assert_eq!(gpst.saturating_sub_weeks(1), Gpst::normalized(0, 10.0));
+        assert_eq!(gpst.saturating_sub_weeks(5), Gpst::normalized(0, 0.0));
+    }
+
+    #[test]
+    fn checked() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        assert_eq!(
+            gpst.checked_add(124187.5).unwrap(),
+            Gpst::normalized(1308, 0.5)
+        );
+        assert_eq!(
+            gpst.checked_sub(480614.0).unwrap(),
+            Gpst::normalized(1306, 604799.0)
+        );
+        assert!(gpst.checked_sub(1e12).is_err());
+        assert!(gpst.checked_add(f64::NAN).is_err());
+        assert!(gpst.checked_add(1e12).is_err());
+        assert!(Gpst::normalized(i64::MAX, 604799.0)
+            .checked_add(1.0)
+            .is_err());
+
+        assert_eq!(gpst.saturating_add(f64::INFINITY).week, i64::MAX);
+        assert_eq!(
+            Gpst::normalized(i64::MAX, 0.0).saturating_sub(1.0),
+            Gpst::normalized(i64::MAX - 1, 604799.0)
+        );
+        assert_eq!(gpst.saturating_sub(f64::INFINITY), Gpst::normalized(0, 0.0));
+        assert_eq!(gpst.saturating_add(f64::NAN), gpst);
+    }
+
+    #[test]
+    fn beyond_2272() {
+        // Week 16696 is in late 2299, past `i64` nanoseconds since GPS Epoch.
+        let gpst = Gpst::normalized(16696, 45.5).with_leap_handling(true);
+        assert_eq!(
+            gpst.checked_add(1.0).unwrap(),
+            Gpst::normalized(16696, 46.5).with_leap_handling(true)
+        );
+        assert_eq!(
+            gpst.checked_sub(46.0).unwrap(),
+            Gpst::normalized(16695, 604799.5).with_leap_handling(true)
+        );
+        assert_eq!(
+            gpst.saturating_add(1.0),
+            Gpst::normalized(16696, 46.5).with_leap_handling(true)
+        );
+        let thirty = TimeDelta::seconds(30);
+        assert_eq!(
+            gpst.floor_to(thirty).unwrap(),
+            Gpst::normalized(16696, 30.0).with_leap_handling(true)
+        );
+        assert_eq!(
+            gpst.round_to(thirty).unwrap(),
+            Gpst::normalized(16696, 60.0).with_leap_handling(true)
+        );
+        assert_eq!(
+            gpst.ceil_to(thirty).unwrap(),
+            Gpst::normalized(16696, 60.0).with_leap_handling(true)
+        );
+        assert!(Gpst::normalized(i64::MAX, 604799.0)
+            .ceil_to(TimeDelta::seconds(7))
+            .is_err());
+    }
+
+    #[test]
+    fn align() {
+        let thirty = TimeDelta::seconds(30);
+        let gpst = Gpst::normalized(1307, 604785.0);
+        assert_eq!(gpst.round_to(thirty).unwrap(), Gpst::normalized(1308, 0.0));
+        assert_eq!(
+            gpst.floor_to(thirty).unwrap(),
+            Gpst::normalized(1307, 604770.0)
+        );
+        assert_eq!(gpst.ceil_to(thirty).unwrap(), Gpst::normalized(1308, 0.0));
+
+        let noisy = Gpst::normalized(1307, 480612.9999999);
+        let second = TimeDelta::seconds(1);
+        assert_eq!(
+            noisy.round_to(second).unwrap(),
+            Gpst::normalized(1307, 480613.0)
+        );
+        let exact = Gpst::normalized(1307, 480600.0);
+        assert_eq!(exact.ceil_to(TimeDelta::seconds(300)).unwrap(), exact);
+        assert!(gpst.round_to(TimeDelta::zero()).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+//! Approximate comparisons within a tolerance, for float time of week values from different sources.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{Gpst, SECONDS_PER_WEEK, TO_NANO_FLOAT};
+
+impl Gpst {
+    /// Whether two epochs are within `tolerance` of each other, comparing the unrounded week seconds. Epochs
+    /// converted with different leap second handling are on different time scales and never compare equal; values
+    /// of unknown handling are assumed to match. A negative tolerance compares by magnitude.
+    pub fn approx_eq(&self, other: &Gpst, tolerance: TimeDelta) -> bool {
+        if self.check_leap_handling(other).is_err() {
+            return false;
+        }
+        let difference = (self.week - other.week) as f64 * SECONDS_PER_WEEK
+            + (self.week_seconds - other.week_seconds);
+        difference.abs() <= tolerance_seconds(tolerance)
+    }
+}
+
+/// Whether two UTC instants are within `tolerance` of each other, e.g. a date-time and its round-trip through
+/// [`Gpst`]. A negative tolerance compares by magnitude.
+pub fn approx_eq_utc(left: &DateTime<Utc>, right: &DateTime<Utc>, tolerance: TimeDelta) -> bool {
+    (*left - *right).abs() <= tolerance.abs()
+}
+
+fn tolerance_seconds(tolerance: TimeDelta) -> f64 {
+    let tolerance = tolerance.abs();
+    tolerance.num_seconds() as f64 + tolerance.subsec_nanos() as f64 / TO_NANO_FLOAT
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{approx_eq_utc, from_gpst, Gpst, GpstLike};
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn gpst() {
+        let gpst = Gpst::new(1307, 480613.0).unwrap();
+        let noisy = Gpst::new(1307, 480613.0000004).unwrap();
+        assert_ne!(gpst, noisy);
+        assert!(gpst.approx_eq(&noisy, TimeDelta::microseconds(1)));
+        assert!(!gpst.approx_eq(&noisy, TimeDelta::nanoseconds(100)));
+        assert!(noisy.approx_eq(&gpst, TimeDelta::microseconds(-1)));
+
+        // Across a week boundary.
+        let end = Gpst::new(1306, 604799.9999999).unwrap();
+        let start = Gpst::new(1307, 0.0).unwrap();
+        assert!(end.approx_eq(&start, TimeDelta::microseconds(1)));
+
+        let stepped = gpst.with_leap_handling(true);
+        assert!(stepped.approx_eq(&gpst, TimeDelta::zero()));
+        assert!(!stepped.approx_eq(&gpst.with_leap_handling(false), TimeDelta::weeks(1)));
+    }
+
+    #[test]
+    fn utc() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let gpst = date_time.gpst(true).unwrap();
+        let round_trip = from_gpst(gpst.week, gpst.week_seconds + 1e-7, true).unwrap();
+        assert_ne!(round_trip, date_time);
+        assert!(approx_eq_utc(
+            &round_trip,
+            &date_time,
+            TimeDelta::microseconds(1)
+        ));
+        assert!(!approx_eq_utc(
+            &round_trip,
+            &date_time,
+            TimeDelta::nanoseconds(10)
+        ));
+    }
+}
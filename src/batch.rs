@@ -0,0 +1,223 @@
+//! Bulk conversions of epoch slices, amortizing the leap second lookup across the batch.
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::{
+    Gpst, GpstError, GpstIteratorExt, LeapHandling, LEAP_NANOSECONDS, LEAP_NANOSECONDS_UTC,
+    LEAP_SECONDS, TO_NANO_INT,
+};
+
+/// Caches the leap second count of the interval between two leap seconds, so runs of nearby epochs (as in
+/// observation files) skip the table lookup.
+pub(crate) struct LeapCursor {
+    boundaries: [i64; LEAP_SECONDS.len()],
+    /// Whether an epoch at a boundary already counts its leap second.
+    inclusive: bool,
+    leaps: usize,
+    low: i64,
+    high: i64,
+}
+
+impl LeapCursor {
+    /// Leap seconds on the GPS scale, counted once strictly past their start as in `num_leaps`.
+    pub(crate) fn gps() -> LeapCursor {
+        LeapCursor::new(LEAP_NANOSECONDS, false)
+    }
+
+    /// Leap seconds on the UTC scale, counted from their start as in `num_leaps_utc`.
+    pub(crate) fn utc() -> LeapCursor {
+        LeapCursor::new(LEAP_NANOSECONDS_UTC, true)
+    }
+
+    fn new(boundaries: [i64; LEAP_SECONDS.len()], inclusive: bool) -> LeapCursor {
+        LeapCursor {
+            boundaries,
+            inclusive,
+            leaps: 0,
+            low: i64::MIN,
+            high: i64::MIN,
+        }
+    }
+
+    /// Leap nanoseconds counted at nanoseconds since GPS Epoch.
+    pub(crate) fn leap_nanoseconds(&mut self, nanoseconds: i64) -> i64 {
+        let cached = if self.inclusive {
+            self.low <= nanoseconds && nanoseconds < self.high
+        } else {
+            self.low < nanoseconds && nanoseconds <= self.high
+        };
+        if !cached {
+            self.leaps = self.boundaries.partition_point(|boundary| {
+                *boundary < nanoseconds || (self.inclusive && *boundary == nanoseconds)
+            });
+            self.low = self
+                .leaps
+                .checked_sub(1)
+                .map_or(i64::MIN, |index| self.boundaries[index]);
+            self.high = self.boundaries.get(self.leaps).copied().unwrap_or(i64::MAX);
+        }
+        self.leaps as i64 * TO_NANO_INT
+    }
+}
+
+/// Epochs per parallel task, so each task amortizes its own leap second lookup.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK: usize = 4096;
+
+/// Convert a slice of UTC instants to GPS Time (GPST), as [`GpstLike::gpst`](crate::GpstLike::gpst) does for each.
+/// Optionally, adjust for leap seconds.
+pub fn gpst_batch(
+    timestamps: &[DateTime<Utc>],
+    leap_seconds: impl Into<LeapHandling>,
+) -> Vec<Result<Gpst, GpstError>> {
+    timestamps.iter().map_to_gpst(leap_seconds).collect()
+}
+
+/// Convert a slice of `(week, week seconds)` pairs to UTC. Optionally, adjust for leap seconds.
+///
+/// Each pair is validated as [`from_gpst`](crate::from_gpst) does, so bad pairs yield an `Err` item.
+pub fn from_gpst_batch(
+    pairs: &[(i64, f64)],
+    leap_seconds: impl Into<LeapHandling>,
+) -> Vec<Result<DateTime<Utc>, GpstError>> {
+    pairs.iter().map_from_gpst(leap_seconds).collect()
+}
+
+/// Parallel [`gpst_batch`], enabled with the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn par_gpst_batch(
+    timestamps: &[DateTime<Utc>],
+    leap_seconds: impl Into<LeapHandling>,
+) -> Vec<Result<Gpst, GpstError>> {
+    let leap_seconds = leap_seconds.into();
+    timestamps
+        .par_chunks(PARALLEL_CHUNK)
+        .flat_map_iter(|chunk| gpst_batch(chunk, leap_seconds))
+        .collect()
+}
+
+/// Parallel [`from_gpst_batch`], enabled with the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn par_from_gpst_batch(
+    pairs: &[(i64, f64)],
+    leap_seconds: impl Into<LeapHandling>,
+) -> Vec<Result<DateTime<Utc>, GpstError>> {
+    let leap_seconds = leap_seconds.into();
+    pairs
+        .par_chunks(PARALLEL_CHUNK)
+        .flat_map_iter(|chunk| from_gpst_batch(chunk, leap_seconds))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, from_gpst_batch, gpst_batch, GpstLike, LeapHandling};
+    use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+
+    /// Epochs every 0.25 s around the 2015-06-30 and 2016-12-31 leap seconds, and across the whole table.
+    fn epochs() -> Vec<DateTime<Utc>> {
+        let mut epochs = Vec::new();
+        for (year, month, day) in [(2015, 6, 30), (2016, 12, 31)] {
+            let start = Utc.with_ymd_and_hms(year, month, day, 23, 59, 58).unwrap();
+            epochs.extend((0..16).map(|step| start + TimeDelta::milliseconds(step * 250)));
+        }
+        let start = Utc.with_ymd_and_hms(1980, 1, 6, 0, 0, 0).unwrap();
+        epochs.extend((0..50).map(|step| start + TimeDelta::days(step * 333)));
+        epochs.push(Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap());
+        epochs
+    }
+
+    #[test]
+    fn matches_single_conversions() {
+        let epochs = epochs();
+        for leap_seconds in [
+            LeapHandling::None,
+            LeapHandling::Step,
+            LeapHandling::Smear24h,
+        ] {
+            let batch = gpst_batch(&epochs, leap_seconds);
+            for (epoch, gpst) in epochs.iter().zip(&batch) {
+                match (epoch.gpst(leap_seconds), gpst) {
+                    (Ok(single), Ok(gpst)) => assert_eq!(single, *gpst),
+                    (single, gpst) => assert!(single.is_err() && gpst.is_err()),
+                }
+            }
+
+            // The inverse round-trips to within the truncation of week seconds to nanoseconds.
+            let (epochs, pairs): (Vec<_>, Vec<_>) = epochs
+                .iter()
+                .zip(&batch)
+                .filter_map(|(epoch, gpst)| Some((*epoch, gpst.as_ref().ok()?)))
+                .map(|(epoch, gpst)| (epoch, (gpst.week, gpst.week_seconds)))
+                .unzip();
+            let date_times = from_gpst_batch(&pairs, leap_seconds);
+            for ((epoch, &(week, week_seconds)), date_time) in
+                epochs.iter().zip(&pairs).zip(date_times)
+            {
+                let date_time = date_time.unwrap();
+                assert!((date_time - *epoch).abs() <= TimeDelta::nanoseconds(2));
+                assert_eq!(
+                    date_time,
+                    from_gpst(week, week_seconds, leap_seconds).unwrap()
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel() {
+        use crate::{par_from_gpst_batch, par_gpst_batch};
+
+        let start = Utc.with_ymd_and_hms(2016, 12, 31, 0, 0, 0).unwrap();
+        let epochs: Vec<_> = (0..20_000)
+            .map(|step| start + TimeDelta::seconds(step * 7))
+            .collect();
+        let batch = par_gpst_batch(&epochs, true);
+        assert_eq!(
+            batch
+                .iter()
+                .map(|gpst| *gpst.as_ref().unwrap())
+                .collect::<Vec<_>>(),
+            gpst_batch(&epochs, true)
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+        );
+        let pairs: Vec<_> = batch
+            .iter()
+            .flatten()
+            .map(|gpst| (gpst.week, gpst.week_seconds))
+            .collect();
+        let date_times: Vec<_> = par_from_gpst_batch(&pairs, true)
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(date_times, epochs);
+    }
+
+    #[test]
+    fn overflow() {
+        let date_times = from_gpst_batch(&[(i64::MAX, 0.0), (1307, 480613.0)], true);
+        assert!(date_times[0].is_err());
+        assert!(date_times[1].is_ok());
+    }
+
+    #[test]
+    fn invalid_pairs() {
+        // Rejected as by from_gpst, instead of NaN converting as the week start or week seconds carrying over.
+        let pairs = [(1307, f64::NAN), (1307, 604800.0), (-1, 0.0)];
+        for (date_time, &(week, week_seconds)) in from_gpst_batch(&pairs, true).iter().zip(&pairs) {
+            assert_eq!(
+                date_time.as_ref().unwrap_err().to_string(),
+                from_gpst(week, week_seconds, true).unwrap_err().to_string()
+            );
+        }
+        #[cfg(feature = "rayon")]
+        assert!(crate::par_from_gpst_batch(&pairs, true)
+            .iter()
+            .all(Result::is_err));
+    }
+}
@@ -0,0 +1,162 @@
+//! `gpst`: convert between UTC (RFC 3339), GPS seconds and GPS week/TOW at a shell prompt, enabled with the `cli`
+//! feature.
+//!
+//! ```text
+//! $ gpst 2005-01-28T13:30:00Z
+//! week 1307, TOW 480613, GPS seconds 790954213
+//! $ gpst 1307 480613
+//! 2005-01-28T13:30:00Z
+//! $ gpst --no-leap 790954213
+//! 2005-01-28T13:30:13Z
+//! ```
+
+use std::io::{self, BufRead};
+use std::process::ExitCode;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use chrono_gpst::{from_gpst, from_gpst_seconds, GpstLike};
+
+const USAGE: &str = "\
+Usage: gpst [--leap | --no-leap] [INPUT...]
+
+Convert between UTC and GPS Time. INPUT is one of:
+  an RFC 3339 date-time   2005-01-28T13:30:00Z  -> week, TOW and GPS seconds
+  GPS seconds             790954213             -> UTC
+  GPS week and TOW        1307 480613           -> UTC
+
+Without INPUT (or with -), convert each line of stdin.
+
+Options:
+  --leap       adjust for leap seconds (default)
+  --no-leap    do not adjust for leap seconds
+  -h, --help   print this help";
+
+fn main() -> ExitCode {
+    let mut leap_seconds = true;
+    let mut words = Vec::new();
+    for argument in std::env::args().skip(1) {
+        match argument.as_str() {
+            "--leap" => leap_seconds = true,
+            "--no-leap" => leap_seconds = false,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            "-" => {}
+            option if option.starts_with("--") => {
+                eprintln!("gpst: unknown option {option}\n\n{USAGE}");
+                return ExitCode::FAILURE;
+            }
+            _ => words.push(argument),
+        }
+    }
+
+    if !words.is_empty() {
+        return report(&words.join(" "), leap_seconds);
+    }
+    let mut status = ExitCode::SUCCESS;
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(error) => {
+                eprintln!("gpst: {error}");
+                return ExitCode::FAILURE;
+            }
+        };
+        if !line.trim().is_empty() && report(&line, leap_seconds) != ExitCode::SUCCESS {
+            status = ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+/// Print the conversion of one input, or the error to stderr.
+fn report(input: &str, leap_seconds: bool) -> ExitCode {
+    match convert(input, leap_seconds) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("gpst: {}: {error}", input.trim());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Convert one input, detecting its form: an RFC 3339 date-time, GPS seconds, or a week and TOW separated by
+/// whitespace or a comma.
+fn convert(input: &str, leap_seconds: bool) -> Result<String, String> {
+    let fields: Vec<&str> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|field| !field.is_empty())
+        .collect();
+    match fields.as_slice() {
+        [date_time] if date_time.contains('T') || date_time.contains('t') => {
+            let date_time = DateTime::parse_from_rfc3339(date_time)
+                .map_err(|error| error.to_string())?
+                .with_timezone(&Utc);
+            let gpst = date_time
+                .gpst(leap_seconds)
+                .map_err(|error| error.to_string())?;
+            let seconds = gpst.week as f64 * 604800.0 + gpst.week_seconds;
+            Ok(format!(
+                "week {}, TOW {}, GPS seconds {seconds}",
+                gpst.week, gpst.week_seconds
+            ))
+        }
+        [seconds] => {
+            let seconds = parse_number(seconds)?;
+            utc(from_gpst_seconds(seconds, leap_seconds))
+        }
+        [week, tow] => {
+            let week = week
+                .parse()
+                .map_err(|_| format!("invalid GPS week {week}"))?;
+            utc(from_gpst(week, parse_number(tow)?, leap_seconds))
+        }
+        _ => Err("expected an RFC 3339 date-time, GPS seconds, or a GPS week and TOW".into()),
+    }
+}
+
+fn parse_number(number: &str) -> Result<f64, String> {
+    number
+        .parse()
+        .map_err(|_| format!("invalid number {number}"))
+}
+
+fn utc(date_time: Result<DateTime<Utc>, chrono_gpst::GpstError>) -> Result<String, String> {
+    date_time
+        .map(|date_time| date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::convert;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(
+            convert("2005-01-28T13:30:00Z", true).unwrap(),
+            "week 1307, TOW 480613, GPS seconds 790954213"
+        );
+        assert_eq!(
+            convert("2005-01-28T14:30:00.5+01:00", false).unwrap(),
+            "week 1307, TOW 480600.5, GPS seconds 790954200.5"
+        );
+        assert_eq!(
+            convert("1307 480613", true).unwrap(),
+            "2005-01-28T13:30:00Z"
+        );
+        assert_eq!(
+            convert(" 1307,480613.25 ", true).unwrap(),
+            "2005-01-28T13:30:00.250Z"
+        );
+        assert_eq!(convert("790954213", true).unwrap(), "2005-01-28T13:30:00Z");
+        assert_eq!(convert("790954213", false).unwrap(), "2005-01-28T13:30:13Z");
+        assert!(convert("1307 604800", true).is_err());
+        assert!(convert("week 1307 tow 0", true).is_err());
+        assert!(convert("2005-01-28T13:30:00", true).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Builder for [`Gpst`], for conversions with more options than fit a positional-argument function.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{from_gpst, Gpst, GpstError, GpstLike, LeapHandling, Round, TimeInput};
+
+/// Builds a validated [`Gpst`] from a week and time of week, GPS seconds or a UTC instant, e.g.
+/// `Gpst::builder().week(2280).tow(345600.5).leap(LeapHandling::Step).build()?`.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct GpstBuilder {
+    week: Option<i64>,
+    tow: Option<f64>,
+    gps_seconds: Option<f64>,
+    utc: Option<DateTime<Utc>>,
+    leap_seconds: Option<LeapHandling>,
+    precision: Option<TimeDelta>,
+    round: Option<Round>,
+}
+
+impl Gpst {
+    /// Start building GPST data, see [`GpstBuilder`].
+    pub fn builder() -> GpstBuilder {
+        GpstBuilder::default()
+    }
+}
+
+impl GpstBuilder {
+    /// Weeks since GPS Epoch, together with [`GpstBuilder::tow`].
+    pub fn week(mut self, week: i64) -> GpstBuilder {
+        self.week = Some(week);
+        self
+    }
+
+    /// Seconds of the GPS week, together with [`GpstBuilder::week`].
+    pub fn tow(mut self, tow: f64) -> GpstBuilder {
+        self.tow = Some(tow);
+        self
+    }
+
+    /// Seconds since GPS Epoch, instead of a week and time of week.
+    pub fn gps_seconds(mut self, gps_seconds: f64) -> GpstBuilder {
+        self.gps_seconds = Some(gps_seconds);
+        self
+    }
+
+    /// A UTC instant to convert, instead of a week and time of week.
+    pub fn utc(mut self, utc: DateTime<Utc>) -> GpstBuilder {
+        self.utc = Some(utc);
+        self
+    }
+
+    /// Leap second handling of UTC conversions, [`LeapHandling::Step`] by default. A week and time of week or GPS
+    /// seconds are taken as already converted with it, and the result records it (see
+    /// [`Gpst::with_leap_handling`]); without it, their result has unknown handling.
+    pub fn leap(mut self, leap_seconds: impl Into<LeapHandling>) -> GpstBuilder {
+        self.leap_seconds = Some(leap_seconds.into());
+        self
+    }
+
+    /// Round to the nearest multiple of a resolution since GPS Epoch, e.g. `TimeDelta::milliseconds(1)`. Not rounded
+    /// by default.
+    pub fn precision(mut self, precision: TimeDelta) -> GpstBuilder {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Round fractional seconds after applying the precision, see [`Round`]. Not rounded by default.
+    pub fn round(mut self, round: Round) -> GpstBuilder {
+        self.round = Some(round);
+        self
+    }
+
+    /// Build the GPST data, failing on missing or conflicting inputs, NaN or infinite seconds, a time of week
+    /// outside of `0..604800` and instants before GPS Epoch.
+    pub fn build(self) -> Result<Gpst, GpstError> {
+        let mut gpst = match (self.week, self.tow, self.gps_seconds, self.utc) {
+            (Some(week), Some(tow), None, None) => Gpst::new(week, tow)?,
+            (None, None, Some(seconds), None) => {
+                if !seconds.is_finite() {
+                    return Err(GpstError::NonFiniteInput(seconds));
+                }
+                if seconds < 0.0 {
+                    return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(seconds)));
+                }
+                Gpst::normalized(0, seconds)
+            }
+            (None, None, None, Some(utc)) => utc.gpst(self.leap_seconds.unwrap_or_default())?,
+            (Some(_), None, None, None) => return Err(GpstError::Builder("week without tow")),
+            (None, Some(_), None, None) => return Err(GpstError::Builder("tow without week")),
+            (None, None, None, None) => return Err(GpstError::Builder("no time input")),
+            _ => return Err(GpstError::Builder("more than one time input")),
+        };
+        if let (Some(leap_seconds), None) = (self.leap_seconds, self.utc) {
+            gpst = gpst.with_leap_handling(leap_seconds);
+        }
+        if let Some(precision) = self.precision {
+            gpst = gpst.round_to(precision)?;
+        }
+        if let Some(round) = self.round {
+            gpst = gpst.rounded(round);
+        }
+        Ok(gpst)
+    }
+
+    /// Build the GPST data as [`GpstBuilder::build`] does and convert it to UTC with the builder's leap second
+    /// handling.
+    pub fn build_utc(self) -> Result<DateTime<Utc>, GpstError> {
+        let gpst = self.build()?;
+        from_gpst(
+            gpst.week,
+            gpst.week_seconds,
+            self.leap_seconds.unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstError, LeapHandling, Round};
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn build() {
+        let gpst = Gpst::builder()
+            .week(2280)
+            .tow(345600.5)
+            .leap(LeapHandling::Step)
+            .build()
+            .unwrap();
+        assert_eq!(
+            gpst,
+            Gpst::normalized(2280, 345600.5).with_leap_handling(LeapHandling::Step)
+        );
+        assert_eq!(
+            Gpst::builder().week(2280).tow(345600.5).build().unwrap(),
+            Gpst::normalized(2280, 345600.5)
+        );
+        assert_eq!(
+            Gpst::builder().gps_seconds(790954213.0).build().unwrap(),
+            Gpst::normalized(1307, 480613.0)
+        );
+
+        let utc = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let gpst = Gpst::builder()
+            .utc(utc + TimeDelta::microseconds(1_600))
+            .precision(TimeDelta::milliseconds(1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            gpst,
+            Gpst::normalized(1307, 480613.002).with_leap_handling(true)
+        );
+        let gpst = Gpst::builder()
+            .week(1307)
+            .tow(480613.75)
+            .round(Round::Floor)
+            .build()
+            .unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.0));
+        // Without a precision or rounding, week seconds are kept as given.
+        let gpst = Gpst::builder()
+            .week(1307)
+            .tow(480612.9999999999)
+            .build()
+            .unwrap();
+        assert_eq!(gpst.week_seconds, 480612.9999999999);
+        let late = Gpst::builder()
+            .week(16696)
+            .tow(45.0004)
+            .precision(TimeDelta::milliseconds(1))
+            .build()
+            .unwrap();
+        assert_eq!(late, Gpst::normalized(16696, 45.0));
+        assert_eq!(
+            Gpst::builder()
+                .week(1307)
+                .tow(480600.0)
+                .leap(false)
+                .build_utc()
+                .unwrap(),
+            utc
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Gpst::builder().week(1307).build(),
+            Err(GpstError::Builder("week without tow"))
+        );
+        assert_eq!(
+            Gpst::builder().week(1307).tow(0.0).gps_seconds(0.0).build(),
+            Err(GpstError::Builder("more than one time input"))
+        );
+        assert!(Gpst::builder().week(1307).tow(604800.0).build().is_err());
+        assert!(Gpst::builder().gps_seconds(f64::NAN).build().is_err());
+        assert!(Gpst::builder()
+            .gps_seconds(0.0)
+            .precision(TimeDelta::zero())
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Compact fixed-size binary encoding of GPST data, e.g. for telemetry links, and serde support built on it.
+//!
+//! The 13 byte layout is little-endian:
+//!
+//! | Bytes  | Field                                                                       |
+//! |--------|-----------------------------------------------------------------------------|
+//! | 0..4   | week, `i32`                                                                 |
+//! | 4..12  | time of week in nanoseconds, `u64`, less than one week                      |
+//! | 12     | flags: leap second handling, 0 unknown, 1 `None`, 2 `Step`, 3 `Smear24h`    |
+//!
+//! The remaining flag bits are reserved and must be zero.
+
+use crate::{Gpst, GpstError, LeapHandling, TimeInput, NANOSECONDS_PER_WEEK};
+
+const LEAP_HANDLING_MASK: u8 = 0b11;
+
+impl Gpst {
+    /// Length of the [`Gpst::to_bytes`] encoding.
+    pub const ENCODED_LEN: usize = 13;
+
+    /// Encode as the 13 byte layout of the [module documentation](self), with the time of week rounded to
+    /// nanoseconds.
+    ///
+    /// Fails on weeks outside of `i32`.
+    pub fn to_bytes(&self) -> Result<[u8; Gpst::ENCODED_LEN], GpstError> {
+        let nanoseconds = self.wide_nanoseconds_since_epoch();
+        let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+        // Split again, as week seconds just below a full week round up into the next week.
+        let week = i32::try_from(nanoseconds.div_euclid(week_nanoseconds)).map_err(|_| {
+            GpstError::Overflow(TimeInput::WeekSeconds(self.week, self.week_seconds))
+        })?;
+        let tow = nanoseconds.rem_euclid(week_nanoseconds) as u64;
+        let flags = match self.leap_handling {
+            None => 0,
+            Some(LeapHandling::None) => 1,
+            Some(LeapHandling::Step) => 2,
+            Some(LeapHandling::Smear24h) => 3,
+        };
+        let mut bytes = [0; Gpst::ENCODED_LEN];
+        bytes[..4].copy_from_slice(&week.to_le_bytes());
+        bytes[4..12].copy_from_slice(&tow.to_le_bytes());
+        bytes[12] = flags;
+        Ok(bytes)
+    }
+
+    /// Decode the layout of [`Gpst::to_bytes`].
+    ///
+    /// Fails if the time of week is not within one week or reserved flag bits are set.
+    pub fn from_bytes(bytes: &[u8; Gpst::ENCODED_LEN]) -> Result<Gpst, GpstError> {
+        let week = i32::from_le_bytes(bytes[..4].try_into().expect("4 bytes"));
+        let tow = u64::from_le_bytes(bytes[4..12].try_into().expect("8 bytes"));
+        let flags = bytes[12];
+        if tow >= NANOSECONDS_PER_WEEK as u64 || flags & !LEAP_HANDLING_MASK != 0 {
+            return Err(GpstError::InvalidBytes(*bytes));
+        }
+        let gpst =
+            Gpst::from_wide_nanoseconds(week as i128 * NANOSECONDS_PER_WEEK as i128 + tow as i128);
+        Ok(match flags {
+            0 => gpst,
+            1 => gpst.with_leap_handling(LeapHandling::None),
+            2 => gpst.with_leap_handling(LeapHandling::Step),
+            _ => gpst.with_leap_handling(LeapHandling::Smear24h),
+        })
+    }
+}
+
+/// Serializes as the [`Gpst::to_bytes`] layout for binary formats such as bincode or postcard, and as a
+/// `{ week, week_seconds, leap_handling }` struct for human-readable formats such as JSON.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Gpst {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            return Readable {
+                week: self.week,
+                week_seconds: self.week_seconds,
+                leap_handling: self.leap_handling,
+            }
+            .serialize(serializer);
+        }
+        let bytes = self.to_bytes().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Gpst {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Gpst, D::Error> {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let readable = Readable::deserialize(deserializer)?;
+            let gpst = Gpst::new(readable.week, readable.week_seconds).map_err(D::Error::custom)?;
+            return Ok(match readable.leap_handling {
+                Some(leap_seconds) => gpst.with_leap_handling(leap_seconds),
+                None => gpst,
+            });
+        }
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+/// The human-readable serde form of [`Gpst`], without the derived `seconds`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Gpst")]
+struct Readable {
+    week: i64,
+    week_seconds: f64,
+    leap_handling: Option<LeapHandling>,
+}
+
+#[cfg(feature = "serde")]
+struct BytesVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for BytesVisitor {
+    type Value = Gpst;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "{} bytes of GPST data", Gpst::ENCODED_LEN)
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, bytes: &[u8]) -> Result<Gpst, E> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| E::invalid_length(bytes.len(), &self))?;
+        Gpst::from_bytes(bytes).map_err(E::custom)
+    }
+
+    // Some formats hand bytes over as a sequence.
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Gpst, A::Error> {
+        let mut bytes = [0; Gpst::ENCODED_LEN];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(index, &self))?;
+        }
+        Gpst::from_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstError, GpstLike, LeapHandling};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn round_trip() {
+        let gpst = Utc
+            .with_ymd_and_hms(2005, 1, 28, 13, 30, 0)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        let bytes = gpst.to_bytes().unwrap();
+        assert_eq!(bytes, [27, 5, 0, 0, 0, 178, 35, 111, 29, 181, 1, 0, 2]);
+        assert_eq!(Gpst::from_bytes(&bytes).unwrap(), gpst);
+
+        for leap_handling in [LeapHandling::None, LeapHandling::Smear24h] {
+            let tagged = gpst.with_leap_handling(leap_handling);
+            assert_eq!(
+                Gpst::from_bytes(&tagged.to_bytes().unwrap()).unwrap(),
+                tagged
+            );
+        }
+        let raw = Gpst::normalized(-1, 0.5);
+        assert_eq!(Gpst::from_bytes(&raw.to_bytes().unwrap()).unwrap(), raw);
+
+        // Every week of the layout decodes, far past the `i64` nanosecond range.
+        for week in [i32::MIN, 15251, i32::MAX] {
+            let gpst = Gpst::normalized(week as i64, 604799.5).with_leap_handling(true);
+            assert_eq!(Gpst::from_bytes(&gpst.to_bytes().unwrap()).unwrap(), gpst);
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            Gpst::normalized(1 << 31, 0.0).to_bytes(),
+            Err(GpstError::Overflow(_))
+        ));
+        let mut bytes = Gpst::normalized(1307, 0.0).to_bytes().unwrap();
+        bytes[12] = 4;
+        assert_eq!(
+            Gpst::from_bytes(&bytes),
+            Err(GpstError::InvalidBytes(bytes))
+        );
+        let mut bytes = [0; Gpst::ENCODED_LEN];
+        bytes[4..12].copy_from_slice(&604800000000000u64.to_le_bytes());
+        assert_eq!(
+            Gpst::from_bytes(&bytes),
+            Err(GpstError::InvalidBytes(bytes))
+        );
+
+        // Hostile input: extreme weeks with the largest valid time of week decode without overflow, and anything
+        // past it is rejected.
+        for week in [i32::MIN, i32::MAX] {
+            let mut bytes = [0xff; Gpst::ENCODED_LEN];
+            bytes[..4].copy_from_slice(&week.to_le_bytes());
+            assert_eq!(
+                Gpst::from_bytes(&bytes),
+                Err(GpstError::InvalidBytes(bytes))
+            );
+            bytes[4..12].copy_from_slice(&604799999999999u64.to_le_bytes());
+            bytes[12] = 3;
+            let gpst = Gpst::from_bytes(&bytes).unwrap();
+            assert_eq!(gpst.week, week as i64);
+            assert_eq!(gpst.to_bytes().unwrap(), bytes);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json() {
+        let gpst = Gpst::normalized(1307, 480613.5).with_leap_handling(true);
+        let json = serde_json::to_string(&gpst).unwrap();
+        assert_eq!(
+            json,
+            r#"{"week":1307,"week_seconds":480613.5,"leap_handling":"Step"}"#
+        );
+        assert_eq!(serde_json::from_str::<Gpst>(&json).unwrap(), gpst);
+        assert!(serde_json::from_str::<Gpst>(
+            r#"{"week":1307,"week_seconds":604800.0,"leap_handling":null}"#
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_bytes() {
+        use super::BytesVisitor;
+        use serde::de::{value::Error, Visitor};
+
+        // The binary formats' path, fed directly, as serde's in-memory deserializers are all human-readable.
+        let gpst = Gpst::normalized(i32::MAX as i64, 604799.5).with_leap_handling(true);
+        let bytes = gpst.to_bytes().unwrap();
+        assert_eq!(BytesVisitor.visit_bytes::<Error>(&bytes).unwrap(), gpst);
+
+        let hostile = [0xff; Gpst::ENCODED_LEN];
+        assert!(BytesVisitor.visit_bytes::<Error>(&hostile).is_err());
+        assert!(BytesVisitor.visit_bytes::<Error>(&hostile[1..]).is_err());
+    }
+}
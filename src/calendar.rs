@@ -0,0 +1,577 @@
+//! Typed GPS calendar units.
+
+use core::ops::{Add, Sub};
+
+use chrono::{
+    DateTime, Datelike, IsoWeek, NaiveDate, NaiveDateTime, NaiveTime, NaiveWeek, TimeDelta, Utc,
+    Weekday,
+};
+
+use crate::{
+    count_leaps, from_wide_gps_nanoseconds, wide_gps_nanoseconds, Gpst, GpstError, GpstLike,
+    LeapHandling, TimeInput, LEAP_SECONDS, NANOSECONDS_PER_WEEK, SECONDS_PER_DAY, SECONDS_PER_WEEK,
+    TO_NANO_FLOAT,
+};
+
+/// Weeks since GPS Epoch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GpsWeek(pub i64);
+
+/// Days since GPS Epoch (the GPS continuous day number), 0 on 1980-01-06.
+///
+/// Signed like [`GpsWeek`], so days before GPS Epoch, as from [`GpsDay::from_date`] or
+/// [`GpstLike::gpst_signed`], are representable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GpsDay(pub i64);
+
+/// Whole seconds since GPS Epoch, with `const` conversions for epoch constants defined at compile time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GpsSeconds(pub i64);
+
+impl From<&Gpst> for GpsWeek {
+    fn from(gpst: &Gpst) -> Self {
+        GpsWeek(gpst.week)
+    }
+}
+
+impl From<&Gpst> for GpsDay {
+    fn from(gpst: &Gpst) -> Self {
+        // Saturates for garbage weeks, beyond `i64` days.
+        GpsDay(
+            gpst.week
+                .saturating_mul(7)
+                .saturating_add((gpst.week_seconds / SECONDS_PER_DAY) as i64),
+        )
+    }
+}
+
+/// GPS Epoch as a calendar date.
+const GPS_EPOCH_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1980, 1, 6) {
+    Some(date) => date,
+    None => panic!("GPS Epoch is a valid date"),
+};
+
+impl GpsWeek {
+    /// The GPS week containing a date. GPS weeks start on Sunday.
+    pub fn from_date(date: NaiveDate) -> GpsWeek {
+        let days = date.signed_duration_since(GPS_EPOCH_DATE).num_days();
+        GpsWeek(days.div_euclid(7))
+    }
+
+    /// The week as a Sunday-aligned chrono [`NaiveWeek`]. `None` if outside chrono's date range.
+    pub fn naive_week(&self) -> Option<NaiveWeek> {
+        Some(self.first_day()?.week(Weekday::Sun))
+    }
+
+    /// The Sunday starting the week. `None` if outside chrono's date range.
+    pub fn first_day(&self) -> Option<NaiveDate> {
+        let days = TimeDelta::try_days(self.0.checked_mul(7)?)?;
+        GPS_EPOCH_DATE.checked_add_signed(days)
+    }
+
+    /// The Saturday ending the week. `None` if outside chrono's date range.
+    pub fn last_day(&self) -> Option<NaiveDate> {
+        self.first_day()?
+            .succ_opt()?
+            .checked_add_signed(TimeDelta::days(5))
+    }
+
+    /// The GPS week sharing Monday to Saturday with an ISO 8601 week. ISO weeks run Monday to Sunday, so the ISO
+    /// week's Sunday falls in the *following* GPS week.
+    pub fn from_iso_week(iso_week: IsoWeek) -> Option<GpsWeek> {
+        let monday = NaiveDate::from_isoywd_opt(iso_week.year(), iso_week.week(), Weekday::Mon)?;
+        Some(GpsWeek::from_date(monday))
+    }
+
+    /// The ISO 8601 week sharing Monday to Saturday with this GPS week. The GPS week's Sunday belongs to the
+    /// *previous* ISO week. `None` if outside chrono's date range.
+    pub fn iso_week(&self) -> Option<IsoWeek> {
+        Some(self.first_day()?.succ_opt()?.iso_week())
+    }
+}
+
+impl GpsDay {
+    /// The GPS day of a date on the GPS calendar.
+    pub fn from_date(date: NaiveDate) -> GpsDay {
+        GpsDay(date.signed_duration_since(GPS_EPOCH_DATE).num_days())
+    }
+
+    /// The calendar date of the day. `None` if outside chrono's date range.
+    pub fn date(&self) -> Option<NaiveDate> {
+        GPS_EPOCH_DATE.checked_add_signed(TimeDelta::try_days(self.0)?)
+    }
+
+    /// The GPS day of a week and day of week (0 = Sunday).
+    pub fn from_week_day(week: GpsWeek, day_of_week: u8) -> Result<GpsDay, GpstError> {
+        if day_of_week > 6 {
+            return Err(GpstError::DayOfWeek(day_of_week));
+        }
+        week.0
+            .checked_mul(7)
+            .and_then(|days| days.checked_add(day_of_week as i64))
+            .map(GpsDay)
+            .ok_or(GpstError::Overflow(TimeInput::Week(week.0)))
+    }
+
+    /// The GPS week containing the day.
+    pub fn week(&self) -> GpsWeek {
+        GpsWeek(self.0.div_euclid(7))
+    }
+
+    /// Day of the GPS week, from 0 (Sunday) to 6 (Saturday).
+    pub fn day_of_week(&self) -> u8 {
+        self.0.rem_euclid(7) as u8
+    }
+
+    /// GPST at the start (`00:00:00` GPS) of the day.
+    pub fn start(&self) -> Gpst {
+        Gpst::normalized(self.week().0, self.day_of_week() as f64 * SECONDS_PER_DAY)
+    }
+
+    /// Move by a number of days, or `None` on `i64` overflow.
+    pub fn checked_add(self, days: i64) -> Option<GpsDay> {
+        self.0.checked_add(days).map(GpsDay)
+    }
+
+    /// Move back by a number of days, or `None` on `i64` overflow.
+    pub fn checked_sub(self, days: i64) -> Option<GpsDay> {
+        self.0.checked_sub(days).map(GpsDay)
+    }
+}
+
+/// # Panics
+/// On `i64` overflow, see [`GpsDay::checked_add`].
+impl Add<i64> for GpsDay {
+    type Output = GpsDay;
+
+    fn add(self, days: i64) -> GpsDay {
+        self.checked_add(days).expect("`GpsDay + i64` overflowed")
+    }
+}
+
+/// # Panics
+/// On `i64` overflow, see [`GpsDay::checked_sub`].
+impl Sub<i64> for GpsDay {
+    type Output = GpsDay;
+
+    fn sub(self, days: i64) -> GpsDay {
+        self.checked_sub(days).expect("`GpsDay - i64` overflowed")
+    }
+}
+
+/// Days between two GPS days.
+///
+/// # Panics
+/// If the days are more than `i64::MAX` apart.
+impl Sub for GpsDay {
+    type Output = i64;
+
+    fn sub(self, other: GpsDay) -> i64 {
+        self.0
+            .checked_sub(other.0)
+            .expect("`GpsDay - GpsDay` overflowed")
+    }
+}
+
+impl Gpst {
+    /// Build GPST data from a calendar date-time on the GPS time scale, i.e. without leap second adjustment.
+    pub(crate) fn from_gps_calendar(date_time: NaiveDateTime) -> Result<Gpst, GpstError> {
+        Ok(Gpst::from_wide_nanoseconds(wide_gps_nanoseconds(
+            &date_time.and_utc(),
+            false,
+        )?))
+    }
+
+    /// The calendar date-time on the GPS time scale. `None` if outside chrono's date range.
+    pub(crate) fn gps_calendar(&self) -> Option<NaiveDateTime> {
+        let date_time =
+            from_wide_gps_nanoseconds(self.wide_nanoseconds_since_epoch(), false).ok()?;
+        Some(date_time.naive_utc())
+    }
+}
+
+impl GpsSeconds {
+    /// GPS seconds from a week and whole seconds of week. Seconds of week outside of the week carry into it.
+    pub const fn from_week_tow(week: i64, tow: i64) -> GpsSeconds {
+        GpsSeconds(week * SECONDS_PER_WEEK as i64 + tow)
+    }
+
+    /// The GPS week containing the second.
+    pub const fn week(self) -> GpsWeek {
+        GpsWeek(self.0.div_euclid(SECONDS_PER_WEEK as i64))
+    }
+
+    /// Whole seconds of the GPS week, from 0 to 604799.
+    pub const fn tow(self) -> i64 {
+        self.0.rem_euclid(SECONDS_PER_WEEK as i64)
+    }
+
+    /// GPS−UTC offset in whole seconds in effect at the second, as [`gps_utc_offset_at`](crate::gps_utc_offset_at).
+    pub const fn gps_utc_offset(self) -> i64 {
+        count_leaps(&LEAP_SECONDS, self.0, false) as i64
+    }
+}
+
+/// GPS seconds from a week and whole seconds of week, as [`GpsSeconds::from_week_tow`].
+pub const fn gps_seconds_from_week_tow(week: i64, tow: i64) -> GpsSeconds {
+    GpsSeconds::from_week_tow(week, tow)
+}
+
+/// The GPS week containing a date, as [`GpsWeek::from_date`].
+pub fn gps_week_of(date: NaiveDate) -> GpsWeek {
+    GpsWeek::from_date(date)
+}
+
+/// The GPS week and day of week (0 for Sunday to 6 for Saturday) of a date, e.g. for daily products without a time
+/// of day. Dates carry no leap seconds, so none are applied.
+pub fn gps_week_and_day(date: NaiveDate) -> (i64, u8) {
+    let day = GpsDay::from_date(date);
+    (day.week().0, day.day_of_week())
+}
+
+/// The date of a GPS week and day of week (0 for Sunday to 6 for Saturday), the inverse of [`gps_week_and_day`].
+///
+/// Fails on days of week past 6 and dates outside of chrono's range.
+pub fn date_from_gps_week_day(week: i64, day_of_week: u8) -> Result<NaiveDate, GpstError> {
+    let out_of_range = GpstError::OutOfRange(TimeInput::Week(week));
+    // Weeks this far out are past chrono's range anyway, and would overflow counting days.
+    if week
+        .checked_mul(7)
+        .and_then(|days| days.checked_add(6))
+        .is_none()
+    {
+        return Err(out_of_range);
+    }
+    GpsDay::from_week_day(GpsWeek(week), day_of_week)?
+        .date()
+        .ok_or(out_of_range)
+}
+
+/// Days from GPS Epoch to January 1 of a year (proleptic Gregorian), negative before 1980.
+const fn days_to_new_year(year: i32) -> i64 {
+    // Days from civil (H. Hinnant), specialised to January 1, so the year before is used for the March-based era.
+    let year = year as i64 - 1;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + 306;
+    // 719468 shifts 0000-03-01 to the Unix epoch, 3657 shifts the Unix epoch to GPS Epoch.
+    era * 146097 + day_of_era - 719468 - 3657
+}
+
+/// The first GPS week beginning (on Sunday) in a year. Weeks are attributed to the year their Sunday falls in, so
+/// this is the week containing January 1 only when the year starts on a Sunday. Negative before 1980.
+pub const fn first_gps_week_of_year(year: i32) -> GpsWeek {
+    GpsWeek(
+        days_to_new_year(year).div_euclid(7) + (days_to_new_year(year).rem_euclid(7) != 0) as i64,
+    )
+}
+
+/// Number of GPS weeks beginning in a year: 52, or 53 when the year has 53 Sundays.
+pub const fn weeks_in_year(year: i32) -> i64 {
+    first_gps_week_of_year(year + 1).0 - first_gps_week_of_year(year).0
+}
+
+impl Gpst {
+    /// Day of the GPS week, from 0 (Sunday) to 6 (Saturday).
+    pub fn day_of_week(&self) -> u8 {
+        ((self.week_seconds / SECONDS_PER_DAY) as u8).min(6)
+    }
+
+    /// Seconds elapsed in the current day of the GPS week.
+    pub fn seconds_of_day(&self) -> f64 {
+        self.week_seconds - self.day_of_week() as f64 * SECONDS_PER_DAY
+    }
+
+    /// Year, day of year (1-based) and seconds of day on the GPS time scale calendar, as used by RINEX and IGS
+    /// product names. This runs ahead of the UTC calendar by the leap seconds applied to this value, so for the UTC
+    /// calendar convert to a `DateTime<Utc>` first. `None` if outside chrono's date range.
+    pub fn to_year_doy_sod(&self) -> Option<(i32, u32, f64)> {
+        let days = TimeDelta::try_days(self.week.checked_mul(7)? + self.day_of_week() as i64)?;
+        let date = GPS_EPOCH_DATE.checked_add_signed(days)?;
+        Some((date.year(), date.ordinal(), self.seconds_of_day()))
+    }
+
+    /// Build GPST data from a UTC year, day of year (1-based) and seconds of day. Optionally, adjust for leap
+    /// seconds.
+    pub fn from_year_doy(
+        year: i32,
+        doy: u32,
+        seconds_of_day: f64,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<Gpst, GpstError> {
+        let date = NaiveDate::from_yo_opt(year, doy).ok_or(GpstError::DayOfYear(year, doy))?;
+        if !(0.0..SECONDS_PER_DAY).contains(&seconds_of_day) {
+            return Err(GpstError::SecondsOfDay(seconds_of_day));
+        }
+        let date_time = date.and_time(NaiveTime::MIN).and_utc()
+            + TimeDelta::nanoseconds((seconds_of_day * TO_NANO_FLOAT).round() as i64);
+        date_time.gpst(leap_seconds)
+    }
+
+    /// Start of the enclosing GPS week (Sunday `00:00:00` GPS).
+    pub fn week_start_gpst(&self) -> Gpst {
+        Gpst::normalized(self.week, 0.0).derived_from(self)
+    }
+
+    /// End of the enclosing GPS week, i.e. the (exclusive) start of the following week.
+    pub fn week_end_gpst(&self) -> Gpst {
+        Gpst::normalized(self.week + 1, 0.0).derived_from(self)
+    }
+
+    /// Start of the enclosing GPS week in UTC. Optionally, adjust for leap seconds.
+    ///
+    /// Fails on GPST data converted from UTC with a different leap handling.
+    pub fn week_start(
+        &self,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<DateTime<Utc>, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        self.check_converted_with(leap_seconds)?;
+        week_boundary(self.week, leap_seconds)
+    }
+
+    /// End of the enclosing GPS week in UTC, i.e. the (exclusive) start of the following week. Optionally, adjust
+    /// for leap seconds.
+    ///
+    /// Fails on GPST data converted from UTC with a different leap handling.
+    pub fn week_end(
+        &self,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<DateTime<Utc>, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        self.check_converted_with(leap_seconds)?;
+        week_boundary(self.week + 1, leap_seconds)
+    }
+
+    /// Days elapsed since GPS Epoch.
+    pub fn gps_day(&self) -> GpsDay {
+        GpsDay::from(self)
+    }
+
+    /// Build GPST data from days since GPS Epoch and seconds of day.
+    pub fn from_gps_day(day: GpsDay, seconds_of_day: f64) -> Result<Gpst, GpstError> {
+        if !(0.0..SECONDS_PER_DAY).contains(&seconds_of_day) {
+            return Err(GpstError::SecondsOfDay(seconds_of_day));
+        }
+        Ok(Gpst::normalized(
+            day.0.div_euclid(7),
+            day.0.rem_euclid(7) as f64 * SECONDS_PER_DAY + seconds_of_day,
+        ))
+    }
+
+    /// Build GPST data from a week, day of week (0 = Sunday) and seconds of day.
+    pub fn from_week_day_seconds(
+        week: i64,
+        day_of_week: u8,
+        seconds_of_day: f64,
+    ) -> Result<Gpst, GpstError> {
+        if day_of_week > 6 {
+            return Err(GpstError::DayOfWeek(day_of_week));
+        }
+        if !(0.0..SECONDS_PER_DAY).contains(&seconds_of_day) {
+            return Err(GpstError::SecondsOfDay(seconds_of_day));
+        }
+        Ok(Gpst::normalized(
+            week,
+            day_of_week as f64 * SECONDS_PER_DAY + seconds_of_day,
+        ))
+    }
+}
+
+/// UTC at the start of a GPS week.
+fn week_boundary(
+    week: i64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    from_wide_gps_nanoseconds(week as i128 * NANOSECONDS_PER_WEEK as i128, leap_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        date_from_gps_week_day, first_gps_week_of_year, gps_seconds_from_week_tow,
+        gps_utc_offset_at, gps_week_and_day, gps_week_of, weeks_in_year, GpsDay, GpsSeconds,
+        GpsWeek, Gpst, GpstError, GpstLike,
+    };
+    use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn week_and_day() {
+        let date = NaiveDate::from_ymd_opt(2005, 1, 28).unwrap();
+        assert_eq!(gps_week_and_day(date), (1307, 5));
+        assert_eq!(date_from_gps_week_day(1307, 5).unwrap(), date);
+        let epoch = NaiveDate::from_ymd_opt(1980, 1, 6).unwrap();
+        assert_eq!(gps_week_and_day(epoch), (0, 0));
+        assert_eq!(gps_week_and_day(epoch.pred_opt().unwrap()), (-1, 6));
+        assert_eq!(
+            date_from_gps_week_day(1307, 7),
+            Err(GpstError::DayOfWeek(7))
+        );
+        assert!(date_from_gps_week_day(i64::MAX / 7, 6).is_err());
+        assert!(date_from_gps_week_day(i64::MIN, 0).is_err());
+    }
+
+    #[test]
+    fn gps_seconds() {
+        const T0: GpsSeconds = gps_seconds_from_week_tow(2280, 0);
+        const WEEK: GpsWeek = T0.week();
+        const OFFSET: i64 = T0.gps_utc_offset();
+        assert_eq!(T0, GpsSeconds(1378944000));
+        assert_eq!(WEEK, GpsWeek(2280));
+        assert_eq!(OFFSET, 18);
+
+        let seconds = GpsSeconds::from_week_tow(1306, 604800 + 480613);
+        assert_eq!((seconds.week(), seconds.tow()), (GpsWeek(1307), 480613));
+        assert_eq!(GpsSeconds(-1).week(), GpsWeek(-1));
+        assert_eq!(GpsSeconds(-1).tow(), 604799);
+
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let gpst = date_time.gpst(true).unwrap();
+        let seconds = GpsSeconds::from_week_tow(gpst.week, gpst.week_seconds as i64);
+        assert_eq!(seconds.gps_utc_offset(), gps_utc_offset_at(date_time));
+        assert_eq!(GpsSeconds(0).gps_utc_offset(), 0);
+    }
+
+    #[test]
+    fn naive_dates() {
+        let date = NaiveDate::from_ymd_opt(2005, 1, 28).unwrap();
+        let week = GpsWeek::from_date(date);
+        assert_eq!(week, GpsWeek(1307));
+        assert_eq!(week.first_day(), NaiveDate::from_ymd_opt(2005, 1, 23));
+        assert_eq!(week.last_day(), NaiveDate::from_ymd_opt(2005, 1, 29));
+        let naive_week = week.naive_week().unwrap();
+        assert_eq!(naive_week.first_day(), week.first_day().unwrap());
+        assert!(naive_week.days().contains(&date));
+        assert_eq!(
+            GpsWeek::from_date(NaiveDate::from_ymd_opt(1980, 1, 5).unwrap()),
+            GpsWeek(-1)
+        );
+    }
+
+    #[test]
+    fn week_boundaries() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        assert_eq!(gpst.week_start_gpst(), Gpst::normalized(1307, 0.0));
+        assert_eq!(gpst.week_end_gpst(), Gpst::normalized(1308, 0.0));
+        assert_eq!(
+            gpst.week_start(true).unwrap(),
+            Utc.with_ymd_and_hms(2005, 1, 22, 23, 59, 47).unwrap()
+        );
+        assert_eq!(
+            gpst.week_end(false).unwrap(),
+            Utc.with_ymd_and_hms(2005, 1, 30, 0, 0, 0).unwrap()
+        );
+        let date = NaiveDate::from_ymd_opt(2005, 1, 28).unwrap();
+        assert_eq!(gps_week_of(date), GpsWeek(1307));
+        assert!(Gpst::normalized(i64::MAX, 0.0).week_start(true).is_err());
+        let step = Utc
+            .with_ymd_and_hms(2005, 1, 28, 13, 30, 0)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        assert!(matches!(
+            step.week_end(false),
+            Err(GpstError::MixedLeapHandling(..))
+        ));
+        assert_eq!(step.week_start(true), gpst.week_start(true));
+
+        // Past `i64` nanoseconds since GPS Epoch, 18 leap seconds behind.
+        let late = Gpst::normalized(16696, 45.5);
+        assert_eq!(
+            late.week_start(true).unwrap(),
+            Utc.with_ymd_and_hms(2299, 12, 30, 23, 59, 42).unwrap()
+        );
+        assert_eq!(
+            late.week_end(false).unwrap(),
+            Utc.with_ymd_and_hms(2300, 1, 7, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            late.gps_calendar().unwrap(),
+            NaiveDate::from_ymd_opt(2299, 12, 31)
+                .unwrap()
+                .and_hms_milli_opt(0, 0, 45, 500)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn iso_weeks() {
+        let iso_week = NaiveDate::from_ymd_opt(2005, 1, 28).unwrap().iso_week();
+        assert_eq!(GpsWeek::from_iso_week(iso_week), Some(GpsWeek(1307)));
+        assert_eq!(GpsWeek(1307).iso_week(), Some(iso_week));
+        // Sunday 2005-01-30 closes ISO week 4, but opens GPS week 1308.
+        let sunday = NaiveDate::from_ymd_opt(2005, 1, 30).unwrap();
+        assert_eq!(sunday.iso_week(), iso_week);
+        assert_eq!(GpsWeek::from_date(sunday), GpsWeek(1308));
+    }
+
+    #[test]
+    fn weeks_per_year() {
+        assert_eq!(first_gps_week_of_year(1980), GpsWeek(0));
+        assert_eq!(first_gps_week_of_year(2005), GpsWeek(1304));
+        assert_eq!(first_gps_week_of_year(2023), GpsWeek(2243));
+        assert_eq!(weeks_in_year(1980), 52);
+        // 2012 is a leap year starting on a Sunday.
+        assert_eq!(weeks_in_year(2012), 53);
+        assert_eq!(weeks_in_year(2013), 52);
+        assert_eq!(first_gps_week_of_year(1979), GpsWeek(-52));
+    }
+
+    #[test]
+    fn gps_day() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        assert_eq!(gpst.gps_day(), GpsDay(9154));
+        assert_eq!(Gpst::from_gps_day(GpsDay(9154), 48613.0).unwrap(), gpst);
+        assert!(Gpst::from_gps_day(GpsDay(9154), -1.0).is_err());
+    }
+
+    #[test]
+    fn gps_day_conversions() {
+        let date = NaiveDate::from_ymd_opt(2005, 1, 28).unwrap();
+        let day = GpsDay::from_date(date);
+        assert_eq!(day, GpsDay(9154));
+        assert_eq!(day.date(), Some(date));
+        assert_eq!((day.week(), day.day_of_week()), (GpsWeek(1307), 5));
+        assert_eq!(GpsDay::from_week_day(GpsWeek(1307), 5).unwrap(), day);
+        assert!(GpsDay::from_week_day(GpsWeek(1307), 7).is_err());
+        assert_eq!(day.start(), Gpst::normalized(1307, 432000.0));
+        assert_eq!(GpsDay(-1).week(), GpsWeek(-1));
+        assert_eq!(GpsDay(-1).day_of_week(), 6);
+
+        assert_eq!(day + 2, GpsDay(9156));
+        assert_eq!((day + 2).week(), GpsWeek(1308));
+        assert_eq!(day - 9154, GpsDay(0));
+        assert_eq!(GpsDay(9160) - day, 6);
+        assert_eq!(day.checked_add(2), Some(GpsDay(9156)));
+        assert_eq!(GpsDay(i64::MAX).checked_add(1), None);
+        assert_eq!(GpsDay(i64::MIN).checked_sub(1), None);
+        assert!(GpsDay::from_week_day(GpsWeek(i64::MAX), 0).is_err());
+        assert_eq!(Gpst::normalized(i64::MAX, 0.0).gps_day(), GpsDay(i64::MAX));
+    }
+
+    #[test]
+    fn year_doy() {
+        let gpst = Gpst::from_year_doy(2005, 28, 48600.0, true).unwrap();
+        assert_eq!(
+            gpst,
+            Gpst::normalized(1307, 480613.0).with_leap_handling(true)
+        );
+        assert_eq!(gpst.to_year_doy_sod(), Some((2005, 28, 48613.0)));
+        let gpst = Gpst::from_year_doy(2005, 28, 48600.0, false).unwrap();
+        assert_eq!(gpst.to_year_doy_sod(), Some((2005, 28, 48600.0)));
+        // Just before UTC midnight, the GPS calendar is already in the next day.
+        let gpst = Gpst::from_year_doy(2016, 366, 86395.0, true).unwrap();
+        assert_eq!(gpst.to_year_doy_sod(), Some((2017, 1, 12.0)));
+        assert!(Gpst::from_year_doy(2005, 366, 0.0, true).is_err());
+    }
+
+    #[test]
+    fn week_day_seconds() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        assert_eq!(gpst.day_of_week(), 5);
+        assert_eq!(gpst.seconds_of_day(), 48613.0);
+        assert_eq!(Gpst::from_week_day_seconds(1307, 5, 48613.0).unwrap(), gpst);
+        assert!(Gpst::from_week_day_seconds(1307, 7, 0.0).is_err());
+        assert!(Gpst::from_week_day_seconds(1307, 0, 86400.0).is_err());
+    }
+}
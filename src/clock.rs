@@ -0,0 +1,21 @@
+//! Clock abstraction, so time-dependent logic can run against a simulated clock.
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current UTC time.
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system clock.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,427 @@
+//! Bulk conversion of delimited timestamp records into CSV or JSON lines.
+//!
+//! Each input line is a record of fields split on a delimiter (quoting is not supported). One field holds a timestamp,
+//! either RFC 3339 UTC or GPS seconds, detected from the column's first value. Output records are built from
+//! [`Column`]s, so the converted times can be written alongside any of the input fields.
+//!
+//! ```
+//! use chrono_gpst::convert::{Column, Format, RecordConverter};
+//!
+//! let input = "time,station\n2005-01-28T13:30:00Z,ALGO\n";
+//! let mut output = Vec::new();
+//! RecordConverter::new()
+//!     .outputs([Column::Input(1), Column::Week, Column::Tow])
+//!     .format(Format::JsonLines)
+//!     .convert(input.as_bytes(), &mut output)
+//!     .unwrap();
+//! assert_eq!(
+//!     String::from_utf8(output).unwrap(),
+//!     "{\"station\":\"ALGO\",\"week\":1307,\"tow\":480613}\n"
+//! );
+//! ```
+
+use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{
+    checked_nanoseconds, Converter, Gpst, GpstError, ParseInput, TimeConverter, TimeInput,
+    SECONDS_PER_WEEK,
+};
+
+/// A column of an output record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Column {
+    /// An input field, copied as is, by index.
+    Input(usize),
+    /// The instant in UTC, as RFC 3339.
+    Utc,
+    /// Seconds since GPS Epoch.
+    GpsSeconds,
+    /// Weeks since GPS Epoch.
+    Week,
+    /// Seconds of the GPS week.
+    Tow,
+}
+
+/// Output record format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Delimited values, with a header line when the input has one.
+    #[default]
+    Csv,
+    /// One JSON object per line, keyed by column name.
+    JsonLines,
+}
+
+/// Form of the timestamps in the input column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    Utc,
+    GpsSeconds,
+}
+
+/// Converts timestamp records, configured with chained setters.
+#[derive(Clone)]
+pub struct RecordConverter {
+    input_column: usize,
+    delimiter: char,
+    header: bool,
+    outputs: Vec<Column>,
+    format: Format,
+    converter: Arc<dyn TimeConverter>,
+}
+
+impl Default for RecordConverter {
+    fn default() -> Self {
+        RecordConverter::new()
+    }
+}
+
+impl RecordConverter {
+    /// Converts timestamps in the first of comma-separated fields, with a header line, to CSV of UTC, week and TOW,
+    /// adjusting for leap seconds.
+    pub fn new() -> RecordConverter {
+        RecordConverter {
+            input_column: 0,
+            delimiter: ',',
+            header: true,
+            outputs: vec![Column::Utc, Column::Week, Column::Tow],
+            format: Format::Csv,
+            converter: Arc::new(Converter::new(true)),
+        }
+    }
+
+    /// Index of the input field holding the timestamp.
+    pub fn input_column(mut self, input_column: usize) -> RecordConverter {
+        self.input_column = input_column;
+        self
+    }
+
+    /// Field delimiter of the input, also used for CSV output.
+    pub fn delimiter(mut self, delimiter: char) -> RecordConverter {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Whether the first input line is a header naming the fields.
+    pub fn header(mut self, header: bool) -> RecordConverter {
+        self.header = header;
+        self
+    }
+
+    /// Columns of each output record.
+    pub fn outputs(mut self, outputs: impl IntoIterator<Item = Column>) -> RecordConverter {
+        self.outputs = outputs.into_iter().collect();
+        self
+    }
+
+    /// Output record format.
+    pub fn format(mut self, format: Format) -> RecordConverter {
+        self.format = format;
+        self
+    }
+
+    /// Conversion policy, [`Converter::new(true)`](Converter::new) by default.
+    pub fn converter(mut self, converter: impl TimeConverter + 'static) -> RecordConverter {
+        self.converter = Arc::new(converter);
+        self
+    }
+
+    /// Convert input lines to output lines (without line terminators), including a CSV header line if the input has
+    /// one. Blank lines are skipped.
+    pub fn records<'a, I>(&'a self, lines: I) -> Records<'a, I::IntoIter>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        Records {
+            converter: self,
+            lines: lines.into_iter(),
+            line_number: 0,
+            names: None,
+            kind: None,
+        }
+    }
+
+    /// Convert the lines of a reader, writing each output line to a writer. Returns the number of converted records,
+    /// and stops at the first error, with conversion errors as [`io::ErrorKind::InvalidData`].
+    pub fn convert(&self, reader: impl BufRead, mut writer: impl Write) -> io::Result<usize> {
+        let mut read_error = None;
+        let mut count: usize = 0;
+        for record in self.records(ReadLines {
+            lines: reader.lines(),
+            error: &mut read_error,
+        }) {
+            let record =
+                record.map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+            writeln!(writer, "{record}")?;
+            count += 1;
+        }
+        if let Some(error) = read_error {
+            return Err(error);
+        }
+        // Discount the CSV header line.
+        let header = self.header && self.format == Format::Csv;
+        Ok(count.saturating_sub(header as usize))
+    }
+
+    fn column_name(&self, column: Column, names: &[String]) -> String {
+        match column {
+            Column::Input(index) => names
+                .get(index)
+                .cloned()
+                .unwrap_or_else(|| format!("column_{index}")),
+            Column::Utc => "utc".into(),
+            Column::GpsSeconds => "gps_seconds".into(),
+            Column::Week => "week".into(),
+            Column::Tow => "tow".into(),
+        }
+    }
+
+    /// Convert one record given its fields.
+    fn record(
+        &self,
+        fields: &[&str],
+        names: &[String],
+        kind: &mut Option<Kind>,
+        line_number: usize,
+    ) -> Result<String, GpstError> {
+        let parse_error =
+            |record: &'static str| GpstError::Parse(ParseInput::at_line(record, line_number));
+        let value = fields
+            .get(self.input_column)
+            .map(|field| field.trim())
+            .ok_or_else(|| parse_error("input field"))?;
+        let kind = *kind.get_or_insert(if value.parse::<f64>().is_ok() {
+            Kind::GpsSeconds
+        } else {
+            Kind::Utc
+        });
+        let (gpst, utc) = match kind {
+            Kind::Utc => {
+                let utc = DateTime::parse_from_rfc3339(value)
+                    .map_err(|_| parse_error("RFC 3339 date-time"))?
+                    .with_timezone(&Utc);
+                (self.converter.to_gpst(&utc)?, utc)
+            }
+            Kind::GpsSeconds => {
+                let seconds: f64 = value.parse().map_err(|_| parse_error("GPS seconds"))?;
+                if !seconds.is_finite() {
+                    return Err(GpstError::NonFiniteInput(seconds));
+                }
+                if seconds < 0.0 {
+                    return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(seconds)));
+                }
+                let nanoseconds = checked_nanoseconds(seconds)
+                    .ok_or(GpstError::Overflow(TimeInput::GpsSeconds(seconds)))?;
+                let gpst = Gpst::from_gps_nanoseconds(nanoseconds);
+                (gpst, self.converter.to_utc(&gpst)?)
+            }
+        };
+
+        let mut values = Vec::with_capacity(self.outputs.len());
+        for column in &self.outputs {
+            let value = match column {
+                Column::Input(index) => {
+                    let field = fields.get(*index).ok_or_else(|| parse_error("field"))?;
+                    match self.format {
+                        Format::Csv => field.to_string(),
+                        Format::JsonLines => json_string(field),
+                    }
+                }
+                Column::Utc => {
+                    let utc = utc.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+                    match self.format {
+                        Format::Csv => utc,
+                        Format::JsonLines => json_string(&utc),
+                    }
+                }
+                Column::GpsSeconds => {
+                    (gpst.week as f64 * SECONDS_PER_WEEK + gpst.week_seconds).to_string()
+                }
+                Column::Week => gpst.week.to_string(),
+                Column::Tow => gpst.week_seconds.to_string(),
+            };
+            values.push(value);
+        }
+        Ok(self.join(values, names))
+    }
+
+    /// Join output values, or header names, into a line.
+    fn join(&self, values: Vec<String>, names: &[String]) -> String {
+        match self.format {
+            Format::Csv => values.join(&self.delimiter.to_string()),
+            Format::JsonLines => {
+                let members: Vec<String> = self
+                    .outputs
+                    .iter()
+                    .zip(values)
+                    .map(|(column, value)| {
+                        format!("{}:{value}", json_string(&self.column_name(*column, names)))
+                    })
+                    .collect();
+                format!("{{{}}}", members.join(","))
+            }
+        }
+    }
+}
+
+/// Iterator of output lines, see [`RecordConverter::records`].
+pub struct Records<'a, I> {
+    converter: &'a RecordConverter,
+    lines: I,
+    line_number: usize,
+    /// Header field names, once the header (if any) is read.
+    names: Option<Vec<String>>,
+    kind: Option<Kind>,
+}
+
+impl<I> Iterator for Records<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Result<String, GpstError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let converter = self.converter;
+        loop {
+            let line = self.lines.next()?;
+            let line = line.as_ref();
+            self.line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split(converter.delimiter).collect();
+            let Some(names) = &self.names else {
+                if !converter.header {
+                    self.names = Some(Vec::new());
+                    return Some(converter.record(&fields, &[], &mut self.kind, self.line_number));
+                }
+                let names: Vec<String> =
+                    fields.iter().map(|name| name.trim().to_string()).collect();
+                let header = converter
+                    .outputs
+                    .iter()
+                    .map(|column| converter.column_name(*column, &names))
+                    .collect::<Vec<_>>()
+                    .join(&converter.delimiter.to_string());
+                self.names = Some(names);
+                match converter.format {
+                    Format::Csv => return Some(Ok(header)),
+                    Format::JsonLines => continue,
+                }
+            };
+            return Some(converter.record(&fields, names, &mut self.kind, self.line_number));
+        }
+    }
+}
+
+/// Lines of a reader, stashing the first read error so [`Records`] can stay over plain strings.
+struct ReadLines<'e, L> {
+    lines: L,
+    error: &'e mut Option<io::Error>,
+}
+
+impl<L: Iterator<Item = io::Result<String>>> Iterator for ReadLines<'_, L> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self.lines.next()? {
+            Ok(line) => Some(line),
+            Err(error) => {
+                *self.error = Some(error);
+                None
+            }
+        }
+    }
+}
+
+/// A JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                json.push_str(&format!("\\u{:04x}", character as u32))
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::convert::{Column, Format, RecordConverter};
+    use crate::{Converter, GpstError};
+
+    #[test]
+    fn csv() {
+        let input = "id;time\n1;2005-01-28T13:30:00Z\n\n2;2005-01-28T13:30:00.5+01:00\n";
+        let converter = RecordConverter::new()
+            .delimiter(';')
+            .input_column(1)
+            .outputs([
+                Column::Input(0),
+                Column::GpsSeconds,
+                Column::Week,
+                Column::Tow,
+            ]);
+        let mut output = Vec::new();
+        assert_eq!(converter.convert(input.as_bytes(), &mut output).unwrap(), 2);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "id;gps_seconds;week;tow\n1;790954213;1307;480613\n2;790950613.5;1307;477013.5\n"
+        );
+    }
+
+    #[test]
+    fn gps_seconds() {
+        let converter = RecordConverter::new()
+            .header(false)
+            .converter(Converter::new(false))
+            .outputs([Column::Utc, Column::Input(1)])
+            .format(Format::JsonLines);
+        let records: Vec<_> = converter
+            .records(["790954213, \"a\"", "790954213.25,b"])
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            records,
+            [
+                r#"{"utc":"2005-01-28T13:30:13Z","column_1":" \"a\""}"#,
+                r#"{"utc":"2005-01-28T13:30:13.250Z","column_1":"b"}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let converter = RecordConverter::new().header(false);
+        let records: Vec<_> = converter
+            .records(["790954213", "2005-01-28T13:30:00Z", "-1", ""])
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert!(records[0].is_ok());
+        // The column was detected as GPS seconds from its first value.
+        assert!(matches!(records[1], Err(GpstError::Parse(input)) if input.line == Some(2)));
+        assert!(matches!(records[2], Err(GpstError::BeforeGPSEpoch(_))));
+
+        let mut output = Vec::new();
+        let error = converter
+            .convert("1,2\nbad".as_bytes(), &mut output)
+            .unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(output, b"1980-01-06T00:00:01Z,0,1\n");
+    }
+}
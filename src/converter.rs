@@ -0,0 +1,116 @@
+//! Converter handles, usable as `dyn TimeConverter` so time policies can be injected at runtime.
+
+use chrono::{DateTime, Utc};
+
+use crate::{from_gpst, Gpst, GpstError, GpstLike, LeapHandling};
+
+/// An object-safe GPST conversion policy.
+///
+/// Plugin frameworks can hold a `Box<dyn TimeConverter>` or `Arc<dyn TimeConverter>` and swap e.g. a simulated
+/// policy for [`Converter`] without generics leaking into their APIs.
+pub trait TimeConverter: Send + Sync {
+    /// Convert a UTC instant to GPS Time (GPST).
+    fn to_gpst(&self, date_time: &DateTime<Utc>) -> Result<Gpst, GpstError>;
+
+    /// Convert GPS Time (GPST) to a UTC instant.
+    fn to_utc(&self, gpst: &Gpst) -> Result<DateTime<Utc>, GpstError>;
+}
+
+/// The standard conversion policy, with a fixed leap second handling.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Converter {
+    leap_seconds: LeapHandling,
+}
+
+impl Converter {
+    /// A converter that optionally adjusts for leap seconds.
+    pub fn new(leap_seconds: impl Into<LeapHandling>) -> Converter {
+        Converter {
+            leap_seconds: leap_seconds.into(),
+        }
+    }
+
+    /// Whether this converter adjusts for leap seconds.
+    pub fn leap_seconds(&self) -> bool {
+        self.leap_seconds != LeapHandling::None
+    }
+
+    /// How this converter handles leap seconds.
+    pub fn leap_handling(&self) -> LeapHandling {
+        self.leap_seconds
+    }
+}
+
+impl TimeConverter for Converter {
+    fn to_gpst(&self, date_time: &DateTime<Utc>) -> Result<Gpst, GpstError> {
+        date_time.gpst(self.leap_seconds)
+    }
+
+    fn to_utc(&self, gpst: &Gpst) -> Result<DateTime<Utc>, GpstError> {
+        gpst.check_converted_with(self.leap_seconds)?;
+        from_gpst(gpst.week, gpst.week_seconds, self.leap_seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Converter, Gpst, GpstError, LeapHandling, TimeConverter};
+    use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+
+    /// A simulation policy running a fixed offset ahead of the real clock.
+    struct Simulated {
+        inner: Box<dyn TimeConverter>,
+        offset: TimeDelta,
+    }
+
+    impl TimeConverter for Simulated {
+        fn to_gpst(&self, date_time: &DateTime<Utc>) -> Result<Gpst, GpstError> {
+            self.inner.to_gpst(&(*date_time + self.offset))
+        }
+
+        fn to_utc(&self, gpst: &Gpst) -> Result<DateTime<Utc>, GpstError> {
+            Ok(self.inner.to_utc(gpst)? - self.offset)
+        }
+    }
+
+    #[test]
+    fn dyn_policies() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let policies: Vec<Box<dyn TimeConverter>> = vec![
+            Box::new(Converter::new(true)),
+            Box::new(Simulated {
+                inner: Box::new(Converter::new(true)),
+                offset: TimeDelta::seconds(60),
+            }),
+        ];
+        let production = policies[0].to_gpst(&date_time).unwrap();
+        assert_eq!(
+            production,
+            Gpst::normalized(1307, 480613.0).with_leap_handling(true)
+        );
+        let simulated = policies[1].to_gpst(&date_time).unwrap();
+        assert_eq!(
+            simulated,
+            Gpst::normalized(1307, 480673.0).with_leap_handling(true)
+        );
+        for policy in &policies {
+            let gpst = policy.to_gpst(&date_time).unwrap();
+            assert_eq!(policy.to_utc(&gpst).unwrap(), date_time);
+        }
+    }
+
+    #[test]
+    fn mixed_leap_handling() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let step = Converter::new(true).to_gpst(&date_time).unwrap();
+        assert_eq!(
+            Converter::new(false).to_utc(&step),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::Step,
+                LeapHandling::None
+            ))
+        );
+        let raw = Gpst::normalized(1307, 480613.0);
+        assert_eq!(Converter::new(true).to_utc(&raw).unwrap(), date_time);
+    }
+}
@@ -0,0 +1,133 @@
+//! Exact decimal GPS seconds and time of week with [`rust_decimal::Decimal`], for users who cannot tolerate `f64`
+//! representation error.
+//!
+//! Conversions go through integer nanoseconds, so they are exact to the nanosecond resolution of
+//! [`chrono::DateTime`]. Finer digits round to the nearest nanosecond, with midpoints away from zero.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{
+    from_wide_gps_nanoseconds, gps_nanoseconds_with, num_leaps_utc, GpstError, LeapHandling,
+    TimeInput, NANOSECONDS_PER_WEEK, SECONDS_PER_WEEK, TO_NANO_INT,
+};
+
+/// Seconds since GPS Epoch for a DateTime<Utc>, as an exact decimal. Optionally, adjust for leap seconds.
+pub fn gpst_seconds_decimal(
+    date_time: &DateTime<Utc>,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<Decimal, GpstError> {
+    let nanoseconds = gps_nanoseconds_with(date_time, leap_seconds.into(), num_leaps_utc)?;
+    Ok(Decimal::from_i128_with_scale(nanoseconds, 9).normalize())
+}
+
+/// Weeks since GPS Epoch and week seconds for a DateTime<Utc>, with the week seconds as an exact decimal.
+/// Optionally, adjust for leap seconds.
+pub fn gpst_week_tow_decimal(
+    date_time: &DateTime<Utc>,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<(i64, Decimal), GpstError> {
+    let nanoseconds = gps_nanoseconds_with(date_time, leap_seconds.into(), num_leaps_utc)?;
+    let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+    let week = nanoseconds.div_euclid(week_nanoseconds) as i64;
+    let tow = Decimal::from_i128_with_scale(nanoseconds.rem_euclid(week_nanoseconds), 9);
+    Ok((week, tow.normalize()))
+}
+
+/// Given decimal seconds since GPS Epoch, convert to a DateTime<Utc>. Optionally, adjust for leap seconds.
+///
+/// Fails on seconds before GPS Epoch.
+pub fn from_gpst_seconds_decimal(
+    seconds: Decimal,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    let lossy = || seconds.to_f64().unwrap_or(f64::NAN);
+    if seconds.is_sign_negative() && !seconds.is_zero() {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(lossy())));
+    }
+    let nanoseconds = decimal_nanoseconds(seconds)
+        .ok_or_else(|| GpstError::OutOfRange(TimeInput::GpsSeconds(lossy())))?;
+    from_wide_gps_nanoseconds(nanoseconds, leap_seconds)
+}
+
+/// Given weeks since GPS Epoch and decimal week seconds, convert to a DateTime<Utc>. Optionally, adjust for leap
+/// seconds.
+///
+/// Fails on week seconds outside of `0..604800` and negative weeks, as [`crate::from_gpst`] does.
+pub fn from_gpst_decimal(
+    week: i64,
+    week_seconds: Decimal,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    let lossy = week_seconds.to_f64().unwrap_or(f64::NAN);
+    if week_seconds.is_sign_negative() && !week_seconds.is_zero()
+        || week_seconds >= Decimal::from(SECONDS_PER_WEEK as i64)
+    {
+        return Err(GpstError::TowOutOfRange {
+            tow: lossy,
+            valid: 0.0..SECONDS_PER_WEEK,
+        });
+    }
+    if week < 0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::Week(week)));
+    }
+    let week_seconds_nanoseconds =
+        decimal_nanoseconds(week_seconds).expect("week seconds within one week");
+    let nanoseconds = week as i128 * NANOSECONDS_PER_WEEK as i128 + week_seconds_nanoseconds;
+    from_wide_gps_nanoseconds(nanoseconds, leap_seconds)
+}
+
+/// Decimal seconds in whole nanoseconds, or `None` if out of `i128` range.
+fn decimal_nanoseconds(seconds: Decimal) -> Option<i128> {
+    seconds
+        .checked_mul(Decimal::from(TO_NANO_INT))?
+        .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+        .to_i128()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        from_gpst_decimal, from_gpst_seconds_decimal, gpst_seconds_decimal, gpst_week_tow_decimal,
+        GpstError,
+    };
+    use chrono::{TimeDelta, TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn exact() {
+        let date_time =
+            Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap() + TimeDelta::nanoseconds(1);
+        let seconds = gpst_seconds_decimal(&date_time, true).unwrap();
+        assert_eq!(seconds, Decimal::from_str("790954213.000000001").unwrap());
+        let (week, tow) = gpst_week_tow_decimal(&date_time, true).unwrap();
+        assert_eq!(
+            (week, tow),
+            (1307, Decimal::from_str("480613.000000001").unwrap())
+        );
+
+        assert_eq!(from_gpst_seconds_decimal(seconds, true).unwrap(), date_time);
+        assert_eq!(from_gpst_decimal(week, tow, true).unwrap(), date_time);
+        let sub_nanosecond = Decimal::from_str("480613.0000000014").unwrap();
+        assert_eq!(
+            from_gpst_decimal(week, sub_nanosecond, true).unwrap(),
+            date_time
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            from_gpst_seconds_decimal(Decimal::from(-1), true),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+        assert!(matches!(
+            from_gpst_decimal(1307, Decimal::from(604800), true),
+            Err(GpstError::TowOutOfRange { .. })
+        ));
+        assert!(from_gpst_decimal(-1, Decimal::ZERO, true).is_err());
+        assert!(from_gpst_seconds_decimal(Decimal::MAX, true).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+//! Epochs carrying both the receiver's nominal time and the clock bias corrected GPS time.
+
+use core::ops::{Add, Sub};
+
+use chrono::TimeDelta;
+
+use crate::{clamp_to_i64, Gpst, TO_NANO_INT};
+
+/// A receiver epoch together with its clock bias corrected GPS Time (GPST), as tracked through RTK pipelines.
+///
+/// The clock bias is the receiver clock error, so `corrected = receiver - clock_bias`. The fields are kept consistent
+/// by construction: arithmetic moves both epochs by the same amount, and changing the bias moves the corrected epoch.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DualTime {
+    receiver: Gpst,
+    corrected: Gpst,
+    clock_bias: TimeDelta,
+}
+
+impl DualTime {
+    /// Pair a receiver epoch with its clock bias.
+    ///
+    /// # Panics
+    /// If the corrected epoch's week overflows `i64`, which only garbage week numbers can cause.
+    pub fn new(receiver: Gpst, clock_bias: TimeDelta) -> DualTime {
+        DualTime {
+            receiver,
+            corrected: shift(&receiver, -clock_bias).expect("corrected week overflowed i64"),
+            clock_bias,
+        }
+    }
+
+    /// The receiver's nominal epoch.
+    pub fn receiver(&self) -> Gpst {
+        self.receiver
+    }
+
+    /// The clock bias corrected epoch.
+    pub fn corrected(&self) -> Gpst {
+        self.corrected
+    }
+
+    /// The receiver clock bias.
+    pub fn clock_bias(&self) -> TimeDelta {
+        self.clock_bias
+    }
+
+    /// Replace the clock bias, e.g. with a new solution, keeping the receiver epoch.
+    pub fn with_clock_bias(self, clock_bias: TimeDelta) -> DualTime {
+        DualTime::new(self.receiver, clock_bias)
+    }
+
+    /// Move both epochs by the same amount, keeping the clock bias, or `None` if either week overflows `i64`.
+    pub fn checked_add(self, delta: TimeDelta) -> Option<DualTime> {
+        Some(DualTime {
+            receiver: shift(&self.receiver, delta)?,
+            corrected: shift(&self.corrected, delta)?,
+            clock_bias: self.clock_bias,
+        })
+    }
+
+    /// Move both epochs back by the same amount, keeping the clock bias, or `None` if either week overflows `i64`.
+    pub fn checked_sub(self, delta: TimeDelta) -> Option<DualTime> {
+        self.checked_add(-delta)
+    }
+
+    /// Time elapsed on the receiver clock since an earlier epoch.
+    pub fn receiver_elapsed(&self, earlier: &DualTime) -> TimeDelta {
+        difference(&self.receiver, &earlier.receiver)
+    }
+}
+
+/// Move both epochs by the same amount, keeping the clock bias.
+///
+/// # Panics
+/// If either week overflows `i64`, see [`DualTime::checked_add`].
+impl Add<TimeDelta> for DualTime {
+    type Output = DualTime;
+
+    fn add(self, delta: TimeDelta) -> DualTime {
+        self.checked_add(delta)
+            .expect("`DualTime + TimeDelta` overflowed the week")
+    }
+}
+
+/// Move both epochs back by the same amount, keeping the clock bias.
+///
+/// # Panics
+/// If either week overflows `i64`, see [`DualTime::checked_sub`].
+impl Sub<TimeDelta> for DualTime {
+    type Output = DualTime;
+
+    fn sub(self, delta: TimeDelta) -> DualTime {
+        self.checked_sub(delta)
+            .expect("`DualTime - TimeDelta` overflowed the week")
+    }
+}
+
+/// True (corrected) time elapsed between two epochs. See [`DualTime::receiver_elapsed`] for the receiver clock.
+impl Sub for DualTime {
+    type Output = TimeDelta;
+
+    fn sub(self, earlier: DualTime) -> TimeDelta {
+        difference(&self.corrected, &earlier.corrected)
+    }
+}
+
+/// Exact in `i128` nanoseconds for any `TimeDelta`, whose range exceeds `i64` nanoseconds.
+fn shift(gpst: &Gpst, delta: TimeDelta) -> Option<Gpst> {
+    let nanoseconds =
+        delta.num_seconds() as i128 * TO_NANO_INT as i128 + delta.subsec_nanos() as i128;
+    Gpst::checked_from_wide_nanoseconds(gpst.wide_nanoseconds_since_epoch() + nanoseconds)
+        .map(|shifted| shifted.derived_from(gpst))
+}
+
+/// Saturates for epochs more than `i64` nanoseconds (about 292 years) apart.
+fn difference(later: &Gpst, earlier: &Gpst) -> TimeDelta {
+    TimeDelta::nanoseconds(clamp_to_i64(
+        later.wide_nanoseconds_since_epoch() - earlier.wide_nanoseconds_since_epoch(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DualTime, Gpst};
+    use chrono::TimeDelta;
+
+    #[test]
+    fn bias() {
+        let receiver = Gpst::normalized(2280, 0.0005);
+        let dual = DualTime::new(receiver, TimeDelta::milliseconds(1));
+        assert_eq!(dual.receiver(), receiver);
+        assert_eq!(dual.corrected(), Gpst::normalized(2279, 604799.9995));
+        let dual = dual.with_clock_bias(TimeDelta::microseconds(-500));
+        assert_eq!(dual.corrected(), Gpst::normalized(2280, 0.001));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let start = DualTime::new(Gpst::normalized(2280, 10.0), TimeDelta::milliseconds(2));
+        let next = start + TimeDelta::seconds(30);
+        assert_eq!(next.receiver(), Gpst::normalized(2280, 40.0));
+        assert_eq!(next.corrected(), Gpst::normalized(2280, 39.998));
+        assert_eq!(next.clock_bias(), start.clock_bias());
+        assert_eq!(next - TimeDelta::seconds(30), start);
+
+        let steered = next.with_clock_bias(TimeDelta::milliseconds(1));
+        assert_eq!(steered - start, TimeDelta::milliseconds(30_001));
+        assert_eq!(steered.receiver_elapsed(&start), TimeDelta::seconds(30));
+    }
+
+    #[test]
+    fn large_deltas() {
+        // 200000 days is past i64 nanoseconds, but well within the week range.
+        let start = DualTime::new(Gpst::normalized(2280, 10.0), TimeDelta::days(200_000));
+        let later = start + TimeDelta::days(200_000);
+        assert_eq!(
+            later.receiver(),
+            Gpst::normalized(2280 + 200_000 / 7, 10.0 + 86400.0 * 3.0)
+        );
+        assert_eq!(later - TimeDelta::days(200_000), start);
+        assert_eq!(later.checked_sub(TimeDelta::days(200_000)), Some(start));
+        assert_eq!(later.corrected(), start.receiver());
+
+        let last = DualTime::new(Gpst::normalized(i64::MAX, 0.0), TimeDelta::zero());
+        assert_eq!(last.checked_add(TimeDelta::weeks(1)), None);
+        assert!(last.checked_sub(TimeDelta::weeks(1)).is_some());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let start = DualTime::new(Gpst::normalized(16696, 10.0), TimeDelta::milliseconds(2));
+        let next = start + TimeDelta::seconds(30);
+        assert_eq!(next.receiver(), Gpst::normalized(16696, 40.0));
+        assert_eq!(next.corrected(), Gpst::normalized(16696, 39.998));
+        assert_eq!(next - start, TimeDelta::seconds(30));
+        assert_eq!(next.receiver_elapsed(&start), TimeDelta::seconds(30));
+    }
+}
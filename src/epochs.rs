@@ -0,0 +1,115 @@
+//! Fixed-interval GPS epoch generation.
+
+use core::iter::FusedIterator;
+
+use chrono::TimeDelta;
+
+use crate::{Gpst, GpstError};
+
+/// Iterator over GPS epochs on a sampling grid, e.g. every 30 s.
+///
+/// Epochs are aligned to multiples of the interval since GPS Epoch, as observation epochs are, and computed in integer
+/// nanoseconds, so week rollovers are exact.
+#[derive(Debug, Clone)]
+pub struct GpstEpochs {
+    start: Gpst,
+    next: i128,
+    end: i128,
+    interval: i128,
+}
+
+impl GpstEpochs {
+    /// Epochs aligned to `interval` from `start` (inclusive) to `end` (exclusive). Epochs carry the leap second
+    /// handling of `start`, and `start` and `end` must not have been converted with different handling.
+    pub fn new(start: Gpst, end: Gpst, interval: TimeDelta) -> Result<GpstEpochs, GpstError> {
+        start.check_leap_handling(&end)?;
+        let interval_nanoseconds = interval
+            .num_nanoseconds()
+            .filter(|nanoseconds| *nanoseconds > 0)
+            .ok_or(GpstError::InvalidInterval(interval))?
+            as i128;
+        let start_nanoseconds = start.wide_nanoseconds_since_epoch();
+        let next = start_nanoseconds
+            + (interval_nanoseconds - start_nanoseconds.rem_euclid(interval_nanoseconds))
+                % interval_nanoseconds;
+        Ok(GpstEpochs {
+            start,
+            next,
+            end: end.wide_nanoseconds_since_epoch(),
+            interval: interval_nanoseconds,
+        })
+    }
+}
+
+impl Iterator for GpstEpochs {
+    type Item = Gpst;
+
+    fn next(&mut self) -> Option<Gpst> {
+        if self.next >= self.end {
+            return None;
+        }
+        let epoch = Gpst::from_wide_nanoseconds(self.next).derived_from(&self.start);
+        self.next = self.next.saturating_add(self.interval);
+        Some(epoch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.next >= self.end {
+            0
+        } else {
+            usize::try_from((self.end - self.next - 1) / self.interval + 1).unwrap_or(usize::MAX)
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GpstEpochs {}
+
+impl FusedIterator for GpstEpochs {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstEpochs};
+    use chrono::TimeDelta;
+
+    #[test]
+    fn aligned() {
+        let start = Gpst::normalized(1307, 604740.5);
+        let end = Gpst::normalized(1308, 60.0);
+        let epochs = GpstEpochs::new(start, end, TimeDelta::seconds(30)).unwrap();
+        assert_eq!(epochs.len(), 3);
+        assert_eq!(
+            epochs.collect::<Vec<_>>(),
+            vec![
+                Gpst::normalized(1307, 604770.0),
+                Gpst::normalized(1308, 0.0),
+                Gpst::normalized(1308, 30.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let start = Gpst::normalized(2280, 0.0);
+        let epochs = GpstEpochs::new(start, start.with_tow(1.0), TimeDelta::milliseconds(100));
+        assert_eq!(epochs.unwrap().count(), 10);
+        let empty = GpstEpochs::new(start, start, TimeDelta::seconds(1)).unwrap();
+        assert_eq!(empty.len(), 0);
+        assert!(GpstEpochs::new(start, start, TimeDelta::zero()).is_err());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let start = Gpst::normalized(20000, 604740.5);
+        let end = Gpst::normalized(20001, 60.0);
+        let epochs = GpstEpochs::new(start, end, TimeDelta::seconds(30)).unwrap();
+        assert_eq!(
+            epochs.collect::<Vec<_>>(),
+            vec![
+                Gpst::normalized(20000, 604770.0),
+                Gpst::normalized(20001, 0.0),
+                Gpst::normalized(20001, 30.0),
+            ]
+        );
+    }
+}
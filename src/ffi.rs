@@ -0,0 +1,234 @@
+//! C interface, enabled with the `ffi` feature, so C and C++ code shares the leap second table and conversions with
+//! Rust. The header is generated to `include/chrono_gpst.h`.
+//!
+//! Every function returns a [`GpstStatus`] and writes its result through out pointers only on success.
+
+use core::ffi::c_char;
+
+use chrono::DateTime;
+
+use crate::{from_gpst, Gpst, GpstError, GpstLike};
+
+/// GPS week and seconds of week.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GpstTime {
+    /// Weeks since GPS Epoch.
+    pub week: i64,
+    /// Seconds of the week, from 0 to 604800 exclusive.
+    pub week_seconds: f64,
+}
+
+/// Result of a C interface call.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GpstStatus {
+    /// The conversion succeeded.
+    Ok = 0,
+    /// An out pointer was null.
+    NullPointer = 1,
+    /// The instant is earlier than GPS Epoch.
+    BeforeGpsEpoch = 2,
+    /// The instant is outside of the supported date range.
+    OutOfRange = 3,
+    /// The input is NaN, infinite, or week seconds are outside of the week.
+    InvalidInput = 4,
+}
+
+impl From<GpstError> for GpstStatus {
+    fn from(error: GpstError) -> Self {
+        match error {
+            GpstError::BeforeGPSEpoch(_) => GpstStatus::BeforeGpsEpoch,
+            GpstError::TimestampNano(_) | GpstError::Overflow(_) | GpstError::OutOfRange(_) => {
+                GpstStatus::OutOfRange
+            }
+            _ => GpstStatus::InvalidInput,
+        }
+    }
+}
+
+impl From<Gpst> for GpstTime {
+    fn from(gpst: Gpst) -> Self {
+        GpstTime {
+            week: gpst.week,
+            week_seconds: gpst.week_seconds,
+        }
+    }
+}
+
+/// Convert a Unix timestamp (UTC) to GPS week and seconds of week. Optionally, adjust for leap seconds.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing a [`GpstTime`].
+#[no_mangle]
+pub unsafe extern "C" fn gpst_from_unix(
+    unix_seconds: i64,
+    nanoseconds: u32,
+    leap_seconds: bool,
+    out: *mut GpstTime,
+) -> GpstStatus {
+    if out.is_null() {
+        return GpstStatus::NullPointer;
+    }
+    let Some(date_time) = DateTime::from_timestamp(unix_seconds, nanoseconds) else {
+        return GpstStatus::OutOfRange;
+    };
+    match date_time.gpst(leap_seconds) {
+        Ok(gpst) => {
+            out.write(gpst.into());
+            GpstStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Convert GPS week and seconds of week to a Unix timestamp (UTC), in whole seconds and nanoseconds. Optionally,
+/// adjust for leap seconds.
+///
+/// # Safety
+///
+/// `unix_seconds` and `nanoseconds` must each be null or valid for writing.
+#[no_mangle]
+pub unsafe extern "C" fn gpst_to_unix(
+    time: GpstTime,
+    leap_seconds: bool,
+    unix_seconds: *mut i64,
+    nanoseconds: *mut u32,
+) -> GpstStatus {
+    if unix_seconds.is_null() || nanoseconds.is_null() {
+        return GpstStatus::NullPointer;
+    }
+    match from_gpst(time.week, time.week_seconds, leap_seconds) {
+        Ok(date_time) => {
+            unix_seconds.write(date_time.timestamp());
+            nanoseconds.write(date_time.timestamp_subsec_nanos());
+            GpstStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// Build a validated [`GpstTime`] from a GPS week and time of week, as `Gpst::new` does.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writing a [`GpstTime`].
+#[no_mangle]
+pub unsafe extern "C" fn gpst_from_week_tow(week: i64, tow: f64, out: *mut GpstTime) -> GpstStatus {
+    if out.is_null() {
+        return GpstStatus::NullPointer;
+    }
+    match Gpst::new(week, tow) {
+        Ok(gpst) => {
+            out.write(gpst.into());
+            GpstStatus::Ok
+        }
+        Err(error) => error.into(),
+    }
+}
+
+/// A static, NUL-terminated description of a status.
+///
+/// Takes the raw status value, since C can pass any `int` as a `GpstStatus`; unknown values get a generic message.
+#[no_mangle]
+pub extern "C" fn gpst_status_message(status: i32) -> *const c_char {
+    let message: &'static core::ffi::CStr = match status {
+        0 => c"ok",
+        1 => c"null out pointer",
+        2 => c"earlier than GPS Epoch",
+        3 => c"outside of the supported date range",
+        4 => c"invalid input",
+        _ => c"unknown status",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::ffi::CStr;
+    use core::ptr;
+
+    use crate::ffi::{
+        gpst_from_unix, gpst_from_week_tow, gpst_status_message, gpst_to_unix, GpstStatus, GpstTime,
+    };
+
+    #[test]
+    fn round_trip() {
+        let mut time = GpstTime {
+            week: 0,
+            week_seconds: 0.0,
+        };
+        // 2005-01-28T13:30:00.5Z
+        unsafe {
+            assert_eq!(
+                gpst_from_unix(1106919000, 500_000_000, true, &mut time),
+                GpstStatus::Ok
+            );
+        }
+        assert_eq!(
+            time,
+            GpstTime {
+                week: 1307,
+                week_seconds: 480613.5
+            }
+        );
+        let (mut unix_seconds, mut nanoseconds) = (0, 0);
+        unsafe {
+            assert_eq!(
+                gpst_to_unix(time, true, &mut unix_seconds, &mut nanoseconds),
+                GpstStatus::Ok
+            );
+        }
+        assert_eq!((unix_seconds, nanoseconds), (1106919000, 500_000_000));
+    }
+
+    #[test]
+    fn errors() {
+        let mut time = GpstTime {
+            week: 0,
+            week_seconds: 0.0,
+        };
+        unsafe {
+            assert_eq!(
+                gpst_from_unix(0, 0, true, &mut time),
+                GpstStatus::BeforeGpsEpoch
+            );
+            assert_eq!(
+                gpst_from_unix(0, 0, true, ptr::null_mut()),
+                GpstStatus::NullPointer
+            );
+            assert_eq!(
+                gpst_from_week_tow(1307, 604800.0, &mut time),
+                GpstStatus::InvalidInput
+            );
+            assert_eq!(
+                gpst_from_week_tow(1307, 480613.0, &mut time),
+                GpstStatus::Ok
+            );
+            assert_eq!(
+                gpst_to_unix(time, true, ptr::null_mut(), ptr::null_mut()),
+                GpstStatus::NullPointer
+            );
+            assert_eq!(
+                CStr::from_ptr(gpst_status_message(GpstStatus::BeforeGpsEpoch as i32)),
+                c"earlier than GPS Epoch"
+            );
+            assert_eq!(
+                CStr::from_ptr(gpst_status_message(GpstStatus::InvalidInput as i32)),
+                c"invalid input"
+            );
+            assert_eq!(CStr::from_ptr(gpst_status_message(5)), c"unknown status");
+            assert_eq!(CStr::from_ptr(gpst_status_message(-1)), c"unknown status");
+        }
+    }
+
+    /// The checked-in header must match the one cbindgen generates from this module.
+    #[test]
+    fn header() {
+        assert_eq!(
+            include_str!("../include/chrono_gpst.h"),
+            include_str!(concat!(env!("OUT_DIR"), "/chrono_gpst.h"))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Random valid GPST epochs for fuzzing and property testing, enabled with the `arbitrary` and `proptest` features.
+//!
+//! Epochs are whole nanoseconds within a week from GPS Epoch up to the last week whose nanoseconds fit in `i64`
+//! (about 2272), so they convert back to UTC.
+
+use crate::{Gpst, NANOSECONDS_PER_WEEK};
+
+/// The last week generated, the last whole week of `i64` nanoseconds since GPS Epoch.
+const MAX_WEEK: i64 = i64::MAX / NANOSECONDS_PER_WEEK as i64 - 1;
+
+/// Build the generated epoch from a week and nanoseconds of week.
+fn generated(week: i64, week_nanoseconds: i64) -> Gpst {
+    Gpst::from_gps_nanoseconds(week * NANOSECONDS_PER_WEEK as i64 + week_nanoseconds)
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Gpst {
+    fn arbitrary(unstructured: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let week = unstructured.int_in_range(0..=MAX_WEEK)?;
+        let week_nanoseconds = unstructured.int_in_range(0..=NANOSECONDS_PER_WEEK as i64 - 1)?;
+        Ok(generated(week, week_nanoseconds))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(2 * size_of::<i64>()))
+    }
+}
+
+/// A proptest strategy of valid GPST epochs, enabled with the `proptest` feature.
+#[cfg(feature = "proptest")]
+pub fn gpst_strategy() -> impl proptest::strategy::Strategy<Value = Gpst> {
+    use proptest::strategy::Strategy;
+
+    (0..=MAX_WEEK, 0..NANOSECONDS_PER_WEEK as i64)
+        .prop_map(|(week, week_nanoseconds)| generated(week, week_nanoseconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, SECONDS_PER_WEEK};
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use crate::Gpst;
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..4096u32).map(|byte| (byte * 167 % 251) as u8).collect();
+        let mut unstructured = Unstructured::new(&bytes);
+        for _ in 0..200 {
+            let gpst = Gpst::arbitrary(&mut unstructured).unwrap();
+            assert!(gpst.week >= 0);
+            assert!((0.0..SECONDS_PER_WEEK).contains(&gpst.week_seconds));
+            assert!(from_gpst(gpst.week, gpst.week_seconds, true).is_ok());
+        }
+        let gpst = Gpst::arbitrary(&mut Unstructured::new(&[0xff; 16])).unwrap();
+        assert!(from_gpst(gpst.week, gpst.week_seconds, true).is_ok());
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn strategy(gpst in crate::gpst_strategy()) {
+            proptest::prop_assert!(gpst.week >= 0);
+            proptest::prop_assert!((0.0..SECONDS_PER_WEEK).contains(&gpst.week_seconds));
+            proptest::prop_assert!(from_gpst(gpst.week, gpst.week_seconds, true).is_ok());
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! GLONASS four-year interval day numbers (N4/NT), as used in GLONASS ephemerides and RTCM 1020.
+//!
+//! Per the GLONASS ICD, N4 numbers four-year intervals from 1996 (N4 = 1 for 1996 to 1999), and NT numbers calendar
+//! days within the interval from 1 on January 1 of its leap year. Both count on GLONASS time, UTC(SU) + 3 hours,
+//! which follows UTC leap seconds.
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc};
+
+use crate::{Gpst, GpstError, GpstLike, TimeInput, SECONDS_PER_DAY, TO_NANO_FLOAT};
+
+/// GLONASS time is UTC(SU), 3 hours ahead of UTC.
+const GLONASS_UTC_OFFSET: TimeDelta = TimeDelta::hours(3);
+/// First year of four-year interval N4 = 1.
+const FIRST_YEAR: i32 = 1996;
+/// N4 is 5 bits in the navigation message, 1 through 31.
+const MAX_N4: u8 = 31;
+
+/// A GLONASS epoch as four-year interval number, day in the interval and seconds of the day on GLONASS time.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GlonassDayTime {
+    /// Four-year interval number, 1 for 1996 to 1999.
+    pub n4: u8,
+    /// Calendar day number within the four-year interval, 1 for January 1 of its leap year.
+    pub nt: u16,
+    /// Seconds of the day on GLONASS time.
+    pub seconds_of_day: f64,
+}
+
+impl GlonassDayTime {
+    /// Build a GLONASS epoch, failing on N4 outside of `1..=31`, NT outside of its interval and seconds of day
+    /// outside of `0..86400`.
+    pub fn new(n4: u8, nt: u16, seconds_of_day: f64) -> Result<GlonassDayTime, GpstError> {
+        let glonass_day_time = GlonassDayTime {
+            n4,
+            nt,
+            seconds_of_day,
+        };
+        glonass_day_time.date()?;
+        if !(0.0..SECONDS_PER_DAY).contains(&seconds_of_day) {
+            return Err(GpstError::SecondsOfDay(seconds_of_day));
+        }
+        Ok(glonass_day_time)
+    }
+
+    /// The GLONASS epoch of a UTC instant, failing before 1996 (GLONASS time) and after the last four-year interval
+    /// N4 can number.
+    pub fn from_utc(date_time: &DateTime<Utc>) -> Result<GlonassDayTime, GpstError> {
+        let out_of_range = || GpstError::OutOfRange(TimeInput::Utc(*date_time));
+        let glonass = date_time
+            .naive_utc()
+            .checked_add_signed(GLONASS_UTC_OFFSET)
+            .ok_or_else(out_of_range)?;
+        let interval = (glonass.year() - FIRST_YEAR).div_euclid(4);
+        if !(0..MAX_N4 as i32).contains(&interval) {
+            return Err(out_of_range());
+        }
+        let start = interval_start(interval as u8 + 1);
+        let nt = (glonass.date() - start).num_days() as u16 + 1;
+        let seconds_of_day = (glonass - glonass.date().and_hms_opt(0, 0, 0).expect("midnight"))
+            .num_nanoseconds()
+            .expect("within one day") as f64
+            / TO_NANO_FLOAT;
+        Ok(GlonassDayTime {
+            n4: interval as u8 + 1,
+            nt,
+            seconds_of_day,
+        })
+    }
+
+    /// The UTC instant of this GLONASS epoch.
+    pub fn to_utc(&self) -> Result<DateTime<Utc>, GpstError> {
+        if !self.seconds_of_day.is_finite() {
+            return Err(GpstError::SecondsOfDay(self.seconds_of_day));
+        }
+        let midnight = self
+            .date()?
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight")
+            .and_utc();
+        let seconds_of_day =
+            TimeDelta::nanoseconds((self.seconds_of_day * TO_NANO_FLOAT).round() as i64);
+        Ok(midnight + seconds_of_day - GLONASS_UTC_OFFSET)
+    }
+
+    /// The GLONASS epoch of a GPS epoch, through UTC with the leap second handling recorded in the value (stepped if
+    /// unknown).
+    pub fn from_gpst(gpst: &Gpst) -> Result<GlonassDayTime, GpstError> {
+        GlonassDayTime::from_utc(&DateTime::<Utc>::try_from(*gpst)?)
+    }
+
+    /// The (leap second adjusted) GPS epoch of this GLONASS epoch.
+    pub fn to_gpst(&self) -> Result<Gpst, GpstError> {
+        self.to_utc()?.gpst(true)
+    }
+
+    /// Calendar date on GLONASS time, failing if N4 or NT are out of range.
+    fn date(&self) -> Result<NaiveDate, GpstError> {
+        let invalid = GpstError::GlonassDay {
+            n4: self.n4,
+            nt: self.nt,
+        };
+        if !(1..=MAX_N4).contains(&self.n4) || self.nt == 0 {
+            return Err(invalid);
+        }
+        let start = interval_start(self.n4);
+        let date = start + TimeDelta::days(self.nt as i64 - 1);
+        // Century years such as 2100 are not leap years, so not every interval has 1461 days.
+        if date >= interval_start(self.n4 + 1) {
+            return Err(invalid);
+        }
+        Ok(date)
+    }
+}
+
+/// January 1 of the first year of four-year interval `n4`.
+fn interval_start(n4: u8) -> NaiveDate {
+    NaiveDate::from_ymd_opt(FIRST_YEAR + 4 * (n4 as i32 - 1), 1, 1).expect("valid year")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GlonassDayTime, GpstError, GpstLike};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn from_utc() {
+        // 16:30 GLONASS time on day 366 + 28 of the 2004 to 2007 interval.
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let glonass = GlonassDayTime::from_utc(&date_time).unwrap();
+        assert_eq!(glonass, GlonassDayTime::new(3, 394, 59400.0).unwrap());
+        assert_eq!(glonass.to_utc().unwrap(), date_time);
+
+        let gpst = date_time.gpst(true).unwrap();
+        assert_eq!(GlonassDayTime::from_gpst(&gpst).unwrap(), glonass);
+        assert_eq!(glonass.to_gpst().unwrap(), gpst);
+    }
+
+    #[test]
+    fn boundaries() {
+        // Midnight GLONASS time is 21:00 UTC the day before.
+        let date_time = Utc.with_ymd_and_hms(2007, 12, 31, 21, 0, 0).unwrap();
+        let glonass = GlonassDayTime::from_utc(&date_time).unwrap();
+        assert_eq!(
+            (glonass.n4, glonass.nt, glonass.seconds_of_day),
+            (4, 1, 0.0)
+        );
+        let date_time = Utc.with_ymd_and_hms(2007, 12, 31, 20, 59, 59).unwrap();
+        let glonass = GlonassDayTime::from_utc(&date_time).unwrap();
+        assert_eq!((glonass.n4, glonass.nt), (3, 1461));
+
+        assert!(
+            GlonassDayTime::from_utc(&Utc.with_ymd_and_hms(1995, 12, 31, 20, 0, 0).unwrap())
+                .is_err()
+        );
+        assert!(GlonassDayTime::new(3, 1461, 0.0).is_ok());
+        assert_eq!(
+            GlonassDayTime::new(3, 1462, 0.0),
+            Err(GpstError::GlonassDay { n4: 3, nt: 1462 })
+        );
+        // 2100 is not a leap year, so the 2100 to 2103 interval (N4 = 27) has 1460 days.
+        assert!(GlonassDayTime::new(27, 1460, 0.0).is_ok());
+        assert!(GlonassDayTime::new(27, 1461, 0.0).is_err());
+        assert!(GlonassDayTime::new(0, 1, 0.0).is_err());
+        assert!(GlonassDayTime::new(1, 1, 86400.0).is_err());
+    }
+}
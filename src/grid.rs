@@ -0,0 +1,89 @@
+//! Snapping of asynchronous epochs onto a regular GPS time grid.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{wide_gps_nanoseconds, Gpst, GpstError, LeapHandling};
+
+/// Snap each UTC epoch to the nearest point of a grid with the given interval, aligned to GPS Epoch on the GPS time
+/// scale. Optionally, adjust for leap seconds.
+///
+/// Returns the grid epoch together with the residual from the grid epoch to the input (positive when the input is
+/// later). Midpoints snap to the later grid epoch. Grid epochs are tagged with the leap handling, as by
+/// [`GpstLike::gpst`](crate::GpstLike::gpst).
+pub fn snap_to_grid(
+    epochs: &[DateTime<Utc>],
+    interval: TimeDelta,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<Vec<(Gpst, TimeDelta)>, GpstError> {
+    let leap_seconds = leap_seconds.into();
+    let interval_nanoseconds = interval
+        .num_nanoseconds()
+        .filter(|nanoseconds| *nanoseconds > 0)
+        .ok_or(GpstError::InvalidInterval(interval))? as i128;
+    epochs
+        .iter()
+        .map(|epoch| {
+            let nanoseconds = wide_gps_nanoseconds(epoch, leap_seconds)?;
+            let residual = (nanoseconds + interval_nanoseconds / 2)
+                .rem_euclid(interval_nanoseconds)
+                - interval_nanoseconds / 2;
+            Ok((
+                Gpst::from_wide_nanoseconds(nanoseconds - residual)
+                    .with_leap_handling(leap_seconds),
+                // Within half an interval, so within `i64` nanoseconds.
+                TimeDelta::nanoseconds(residual as i64),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, snap_to_grid, Gpst, LeapHandling};
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn snap() {
+        let base = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let epochs = [
+            base + TimeDelta::milliseconds(12_300),
+            base + TimeDelta::milliseconds(2_000),
+            base - TimeDelta::milliseconds(13_500),
+        ];
+        let snapped = snap_to_grid(&epochs, TimeDelta::seconds(30), true).unwrap();
+        // 13:30:00 UTC is 480613 s into the GPS week, so grid epochs fall at 480600 + 30 s steps.
+        assert_eq!(
+            snapped[0],
+            (
+                Gpst::normalized(1307, 480630.0).with_leap_handling(true),
+                TimeDelta::milliseconds(-4_700)
+            )
+        );
+        assert_eq!(
+            snapped[1],
+            (
+                Gpst::normalized(1307, 480630.0).with_leap_handling(true),
+                TimeDelta::milliseconds(-15_000)
+            )
+        );
+        assert_eq!(
+            snapped[2],
+            (
+                Gpst::normalized(1307, 480600.0).with_leap_handling(true),
+                TimeDelta::milliseconds(-500)
+            )
+        );
+        assert!(snap_to_grid(&epochs, TimeDelta::zero(), true).is_err());
+
+        // Past i64 nanoseconds since GPS Epoch, in 2300.
+        let late = Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 7).unwrap();
+        let snapped = snap_to_grid(&[late], TimeDelta::seconds(30), true).unwrap();
+        assert_eq!(snapped[0].1, TimeDelta::seconds(-5));
+        assert_eq!(
+            from_gpst(snapped[0].0.week, snapped[0].0.week_seconds, true).unwrap(),
+            late + TimeDelta::seconds(5)
+        );
+        let raw = snap_to_grid(&epochs, TimeDelta::seconds(30), false).unwrap();
+        assert_eq!(raw[0].0.leap_handling, Some(LeapHandling::None));
+    }
+}
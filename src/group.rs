@@ -0,0 +1,69 @@
+//! Partitioning of epochs by GPS calendar units.
+
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+
+use crate::{GpsDay, GpsWeek, Gpst};
+
+/// Group epochs by a key, yielding groups in ascending key order. Items keep their input order within a group.
+fn group_by<K, T, I>(epochs: I, key: impl Fn(&Gpst) -> K) -> impl Iterator<Item = (K, Vec<T>)>
+where
+    K: Ord,
+    T: Borrow<Gpst>,
+    I: IntoIterator<Item = T>,
+{
+    let mut groups: BTreeMap<K, Vec<T>> = BTreeMap::new();
+    for epoch in epochs {
+        groups.entry(key(epoch.borrow())).or_default().push(epoch);
+    }
+    groups.into_iter()
+}
+
+/// Group epochs by GPS week, yielding `(week, epochs)` in ascending week order.
+pub fn group_by_gps_week<T, I>(epochs: I) -> impl Iterator<Item = (GpsWeek, Vec<T>)>
+where
+    T: Borrow<Gpst>,
+    I: IntoIterator<Item = T>,
+{
+    group_by(epochs, |gpst| GpsWeek::from(gpst))
+}
+
+/// Group epochs by GPS day, yielding `(day, epochs)` in ascending day order.
+pub fn group_by_gps_day<T, I>(epochs: I) -> impl Iterator<Item = (GpsDay, Vec<T>)>
+where
+    T: Borrow<Gpst>,
+    I: IntoIterator<Item = T>,
+{
+    group_by(epochs, |gpst| GpsDay::from(gpst))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{group_by_gps_day, group_by_gps_week, GpsDay, GpsWeek, Gpst};
+
+    #[test]
+    fn weeks() {
+        let epochs = [
+            Gpst::normalized(1308, 10.0),
+            Gpst::normalized(1307, 480613.0),
+            Gpst::normalized(1308, 20.0),
+        ];
+        let groups: Vec<_> = group_by_gps_week(&epochs).collect();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0], (GpsWeek(1307), vec![&epochs[1]]));
+        assert_eq!(groups[1], (GpsWeek(1308), vec![&epochs[0], &epochs[2]]));
+    }
+
+    #[test]
+    fn days() {
+        let epochs = vec![
+            Gpst::normalized(1307, 86399.0),
+            Gpst::normalized(1307, 86400.0),
+            Gpst::normalized(1307, 0.0),
+        ];
+        let groups: Vec<_> = group_by_gps_day(epochs).collect();
+        assert_eq!(groups[0].0, GpsDay(1307 * 7));
+        assert_eq!(groups[0].1.len(), 2);
+        assert_eq!(groups[1].0, GpsDay(1307 * 7 + 1));
+    }
+}
@@ -0,0 +1,130 @@
+//! Interop with [hifitime](https://docs.rs/hifitime/latest/hifitime/), enabled with the `hifitime` feature.
+//!
+//! A hifitime [`Epoch`] is an absolute instant, so conversions always use the leap second adjusted GPS time scale,
+//! i.e. the same values as `gpst(true)` / `from_gpst(.., true)`. GPST data converted with other leap second handling
+//! is rebased onto it first.
+
+#[cfg(any(test, feature = "hifitime-oracle"))]
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use hifitime::{Duration, Epoch};
+
+use crate::{Gpst, GpstError, LeapHandling, TimeInput};
+
+/// Every hifitime epoch, some 3.3 million years either way, is within the weeks of [`Gpst`].
+impl From<Epoch> for Gpst {
+    fn from(epoch: Epoch) -> Self {
+        let nanoseconds = epoch.to_gpst_duration().total_nanoseconds();
+        Gpst::from_wide_nanoseconds(nanoseconds).with_leap_handling(LeapHandling::Step)
+    }
+}
+
+/// Values of unknown leap second handling are taken as already adjusted. Fails if rebasing fails, or beyond the
+/// range of hifitime.
+impl TryFrom<Gpst> for Epoch {
+    type Error = GpstError;
+
+    fn try_from(gpst: Gpst) -> Result<Epoch, GpstError> {
+        let gpst = match gpst.leap_handling {
+            None | Some(LeapHandling::Step) => gpst,
+            Some(_) => gpst.rebase(LeapHandling::Step)?,
+        };
+        let nanoseconds = gpst.wide_nanoseconds_since_epoch();
+        let range = Duration::MIN.total_nanoseconds()..=Duration::MAX.total_nanoseconds();
+        if !range.contains(&nanoseconds) {
+            return Err(GpstError::OutOfRange(TimeInput::GpsNanoseconds(
+                nanoseconds,
+            )));
+        }
+        Ok(Epoch::from_gpst_duration(Duration::from_total_nanoseconds(
+            nanoseconds,
+        )))
+    }
+}
+
+/// The hifitime [`Epoch`] of a UTC instant, for cross-checks against hifitime.
+#[cfg(any(test, feature = "hifitime-oracle"))]
+pub(crate) fn epoch_from_utc(date_time: &DateTime<Utc>) -> Epoch {
+    Epoch::from_gregorian_utc(
+        date_time.year(),
+        date_time.month() as u8,
+        date_time.day() as u8,
+        date_time.hour() as u8,
+        date_time.minute() as u8,
+        date_time.second() as u8,
+        date_time.nanosecond(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::epoch_from_utc;
+    use crate::{
+        from_gpst, Gpst, GpstError, GpstLike, LeapHandling, GPS_EPOCH, LEAP_SECONDS, TO_NANO_INT,
+    };
+    use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    use hifitime::{Duration, Epoch};
+
+    /// Cross-check a UTC instant against hifitime's GPST scale, in both directions.
+    fn cross_check(date_time: DateTime<Utc>) {
+        let expected = Gpst::from(epoch_from_utc(&date_time));
+        let gpst = date_time.gpst(true).unwrap();
+        assert_eq!(gpst.week, expected.week, "{date_time}");
+        assert_eq!(gpst.week_seconds, expected.week_seconds, "{date_time}");
+        assert_eq!(
+            from_gpst(expected.week, expected.week_seconds, true).unwrap(),
+            date_time
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let epoch = Epoch::from_gregorian_utc(2005, 1, 28, 13, 30, 0, 0);
+        let gpst = Gpst::from(epoch);
+        assert_eq!(gpst.week, 1307);
+        assert_eq!(gpst.week_seconds, 480613.0);
+        assert_eq!(Epoch::try_from(gpst).unwrap(), epoch);
+        assert_eq!(Epoch::try_from(Gpst::normalized(1307, 480613.0)), Ok(epoch));
+    }
+
+    #[test]
+    fn leap_handling() {
+        // The same UTC instant converted without leap seconds is rebased onto the adjusted scale.
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let epoch = epoch_from_utc(&date_time);
+        let unadjusted = date_time.gpst(false).unwrap();
+        assert_eq!(Epoch::try_from(unadjusted), Ok(epoch));
+        let smeared = date_time.gpst(LeapHandling::Smear24h).unwrap();
+        assert_eq!(Epoch::try_from(smeared), Ok(epoch));
+    }
+
+    #[test]
+    fn range() {
+        // Past 2272, beyond `i64` nanoseconds since GPS Epoch, and at the ends of hifitime's range.
+        let late = Gpst::normalized(20000, 480613.25).with_leap_handling(true);
+        assert_eq!(Gpst::from(Epoch::try_from(late).unwrap()), late);
+        for epoch in [
+            Epoch::from_gpst_duration(Duration::MAX),
+            Epoch::from_gpst_duration(Duration::MIN),
+        ] {
+            let gpst = Gpst::from(epoch);
+            assert_eq!(
+                gpst.wide_nanoseconds_since_epoch(),
+                epoch.to_gpst_duration().total_nanoseconds()
+            );
+        }
+        assert!(matches!(
+            Epoch::try_from(Gpst::normalized(i64::MAX, 0.0)),
+            Err(GpstError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn matches_hifitime_around_leap_seconds() {
+        for leap_second in LEAP_SECONDS {
+            let leap = DateTime::from_timestamp(leap_second + GPS_EPOCH / TO_NANO_INT, 0).unwrap();
+            for offset in -3..=20 {
+                cross_check(leap + TimeDelta::seconds(offset));
+            }
+        }
+    }
+}
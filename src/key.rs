@@ -0,0 +1,120 @@
+//! Fixed-width integer keys for GPST data, e.g. for database or Parquet columns.
+//!
+//! The key packs the week into the high 14 bits and the time of week in nanoseconds into the low 50 bits, so keys
+//! order as the epochs they encode and the week and time of week can be read back with shifts and masks. Store keys
+//! as big-endian bytes ([`u64::to_be_bytes`]) where byte-wise ordering matters.
+
+use crate::{Gpst, GpstError, TimeInput, NANOSECONDS_PER_WEEK};
+
+/// Bits of the key holding the time of week in nanoseconds; one week is about 2^49.1 nanoseconds.
+const TOW_BITS: u32 = 50;
+const TOW_MASK: u64 = (1 << TOW_BITS) - 1;
+/// Weeks the remaining 14 bits can hold, through week 16383 (the year 2294).
+const MAX_WEEK: i64 = (1 << (u64::BITS - TOW_BITS)) - 1;
+
+impl Gpst {
+    /// Encode as a monotonic integer key, the week in the high 14 bits and the time of week in nanoseconds (rounded
+    /// from the week seconds) in the low 50 bits.
+    ///
+    /// Fails on negative weeks and weeks past 16383.
+    pub fn to_sortable_u64(&self) -> Result<u64, GpstError> {
+        let nanoseconds = self.wide_nanoseconds_since_epoch();
+        let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+        // Split again, as week seconds just below a full week round up into the next week.
+        let week = nanoseconds.div_euclid(week_nanoseconds);
+        let tow = nanoseconds.rem_euclid(week_nanoseconds) as u64;
+        if week < 0 {
+            return Err(GpstError::BeforeGPSEpoch(TimeInput::Week(self.week)));
+        }
+        if week > MAX_WEEK as i128 {
+            return Err(GpstError::Overflow(TimeInput::WeekSeconds(
+                self.week,
+                self.week_seconds,
+            )));
+        }
+        Ok((week as u64) << TOW_BITS | tow)
+    }
+
+    /// Decode a key from [`Gpst::to_sortable_u64`]. The key does not record leap second handling, so the result has
+    /// none; see [`Gpst::with_leap_handling`].
+    ///
+    /// Fails if the time of week bits are not within one week.
+    pub fn from_sortable_u64(key: u64) -> Result<Gpst, GpstError> {
+        let week = (key >> TOW_BITS) as i128;
+        let tow = key & TOW_MASK;
+        if tow >= NANOSECONDS_PER_WEEK as u64 {
+            return Err(GpstError::SortableKey(key));
+        }
+        Ok(Gpst::from_wide_nanoseconds(
+            week * NANOSECONDS_PER_WEEK as i128 + tow as i128,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstError, GpstLike};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn round_trip() {
+        let gpst = Gpst::new(1307, 480613.000000001).unwrap();
+        let key = gpst.to_sortable_u64().unwrap();
+        assert_eq!(key >> 50, 1307);
+        assert_eq!(key & ((1 << 50) - 1), 480613000000001);
+        assert_eq!(Gpst::from_sortable_u64(key).unwrap(), gpst);
+
+        let tagged = Utc
+            .with_ymd_and_hms(2005, 1, 28, 13, 30, 0)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        let decoded = Gpst::from_sortable_u64(tagged.to_sortable_u64().unwrap()).unwrap();
+        assert_eq!(decoded.with_leap_handling(true), tagged);
+
+        // Past the `i64` nanosecond range, up to the last week the key holds.
+        let last = Gpst::new(16383, 604799.999999999).unwrap();
+        let key = last.to_sortable_u64().unwrap();
+        assert_eq!(key >> 50, 16383);
+        assert_eq!(Gpst::from_sortable_u64(key).unwrap(), last);
+    }
+
+    #[test]
+    fn ordering() {
+        let epochs = [
+            Gpst::new(0, 0.0).unwrap(),
+            Gpst::new(1307, 480613.0).unwrap(),
+            Gpst::new(1307, 604799.999999999).unwrap(),
+            Gpst::new(1308, 0.0).unwrap(),
+            Gpst::new(16383, 604799.0).unwrap(),
+        ];
+        let keys: Vec<u64> = epochs
+            .iter()
+            .map(|gpst| gpst.to_sortable_u64().unwrap())
+            .collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+        let bytes: Vec<[u8; 8]> = keys.iter().map(|key| key.to_be_bytes()).collect();
+        assert!(bytes.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Week seconds rounding to a full week carry into the next week.
+        let carry = Gpst::new(1307, 604799.9999999999).unwrap();
+        assert_eq!(carry.to_sortable_u64().unwrap(), 1308 << 50);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            Gpst::new(16384, 0.0).unwrap().to_sortable_u64(),
+            Err(GpstError::Overflow(_))
+        ));
+        assert!(matches!(
+            Gpst::normalized(-1, 0.0).to_sortable_u64(),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+        let key = (1307 << 50) | 604800000000000;
+        assert_eq!(
+            Gpst::from_sortable_u64(key),
+            Err(GpstError::SortableKey(key))
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! Latency of GNSS observations, for monitoring real-time streams.
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{wide_gps_nanoseconds, Clock, Gpst, GpstError, LeapHandling, TimeInput};
+
+/// Delay between an observation epoch and its arrival.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Latency(TimeDelta);
+
+/// Classification of a [`Latency`] against [`LatencyThresholds`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LatencyClass {
+    /// Arrived before its epoch, which indicates clock skew between the source and the receiving clock.
+    Future,
+    /// Within the degraded threshold.
+    Nominal,
+    /// At or beyond the degraded threshold.
+    Degraded,
+    /// At or beyond the stale threshold.
+    Stale,
+}
+
+/// Thresholds for classifying a [`Latency`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LatencyThresholds {
+    /// Latency from which observations are degraded.
+    pub degraded: TimeDelta,
+    /// Latency from which observations are stale.
+    pub stale: TimeDelta,
+}
+
+impl Latency {
+    /// Latency of an observation arriving at a UTC instant. Optionally, adjust for leap seconds, which must match
+    /// how the observation epoch was produced, or this fails with [`GpstError::MixedLeapHandling`].
+    pub fn measure(
+        observation: &Gpst,
+        arrival: DateTime<Utc>,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<Latency, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        observation.check_converted_with(leap_seconds)?;
+        let arrival = wide_gps_nanoseconds(&arrival, leap_seconds)?;
+        let nanoseconds = arrival - observation.wide_nanoseconds_since_epoch();
+        i64::try_from(nanoseconds)
+            .map(|nanoseconds| Latency(TimeDelta::nanoseconds(nanoseconds)))
+            .map_err(|_| GpstError::Overflow(TimeInput::GpsNanoseconds(nanoseconds)))
+    }
+
+    /// Latency of an observation arriving now, according to a clock.
+    pub fn since(
+        observation: &Gpst,
+        clock: &impl Clock,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<Latency, GpstError> {
+        Latency::measure(observation, clock.now(), leap_seconds)
+    }
+
+    /// The delay, negative when the observation arrived before its epoch.
+    pub fn delay(&self) -> TimeDelta {
+        self.0
+    }
+
+    /// Classify against thresholds.
+    pub fn classify(&self, thresholds: &LatencyThresholds) -> LatencyClass {
+        if self.0 < TimeDelta::zero() {
+            LatencyClass::Future
+        } else if self.0 >= thresholds.stale {
+            LatencyClass::Stale
+        } else if self.0 >= thresholds.degraded {
+            LatencyClass::Degraded
+        } else {
+            LatencyClass::Nominal
+        }
+    }
+}
+
+/// Aggregate of recorded latencies, for percentile reporting.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyStats {
+    latencies: Vec<Latency>,
+    sorted: bool,
+}
+
+impl LatencyStats {
+    /// An empty aggregate.
+    pub fn new() -> LatencyStats {
+        LatencyStats::default()
+    }
+
+    /// Record a latency.
+    pub fn record(&mut self, latency: Latency) {
+        self.latencies.push(latency);
+        self.sorted = false;
+    }
+
+    /// Number of recorded latencies.
+    pub fn len(&self) -> usize {
+        self.latencies.len()
+    }
+
+    /// Whether no latencies have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.latencies.is_empty()
+    }
+
+    /// Nearest-rank percentile, for `percentile` in `0.0..=100.0`. `None` if nothing has been recorded.
+    pub fn percentile(&mut self, percentile: f64) -> Option<Latency> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+        if !self.sorted {
+            self.latencies.sort_unstable();
+            self.sorted = true;
+        }
+        let rank = (percentile.clamp(0.0, 100.0) / 100.0 * self.latencies.len() as f64).ceil();
+        let index = (rank as usize).clamp(1, self.latencies.len()) - 1;
+        Some(self.latencies[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Clock, Gpst, GpstError, GpstLike, Latency, LatencyClass, LatencyStats, LatencyThresholds,
+        LeapHandling,
+    };
+    use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn measure() {
+        let epoch = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let observation = epoch.gpst(true).unwrap();
+        let clock = FixedClock(epoch + TimeDelta::milliseconds(1_500));
+        let latency = Latency::since(&observation, &clock, true).unwrap();
+        assert_eq!(latency.delay(), TimeDelta::milliseconds(1_500));
+
+        let thresholds = LatencyThresholds {
+            degraded: TimeDelta::seconds(1),
+            stale: TimeDelta::seconds(5),
+        };
+        assert_eq!(latency.classify(&thresholds), LatencyClass::Degraded);
+        let early = Latency::measure(&observation, epoch - TimeDelta::seconds(1), true).unwrap();
+        assert_eq!(early.classify(&thresholds), LatencyClass::Future);
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let epoch = Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap();
+        let observation = epoch.gpst(true).unwrap();
+        let latency =
+            Latency::measure(&observation, epoch + TimeDelta::milliseconds(250), true).unwrap();
+        assert_eq!(latency.delay(), TimeDelta::milliseconds(250));
+        assert!(Latency::measure(&Gpst::normalized(0, 0.0), epoch, true).is_err());
+    }
+
+    #[test]
+    fn mixed_leap_handling() {
+        let epoch = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let observation = epoch.gpst(true).unwrap();
+        assert_eq!(
+            Latency::measure(&observation, epoch, false),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::Step,
+                LeapHandling::None
+            ))
+        );
+    }
+
+    #[test]
+    fn percentiles() {
+        let observation = Gpst::normalized(1307, 480613.0);
+        let mut stats = LatencyStats::new();
+        assert_eq!(stats.percentile(50.0), None);
+        let epoch = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        for milliseconds in (1..=100).rev() {
+            let arrival = epoch + TimeDelta::milliseconds(milliseconds);
+            stats.record(Latency::measure(&observation, arrival, true).unwrap());
+        }
+        assert_eq!(stats.len(), 100);
+        assert_eq!(
+            stats.percentile(50.0).unwrap().delay(),
+            TimeDelta::milliseconds(50)
+        );
+        assert_eq!(
+            stats.percentile(95.0).unwrap().delay(),
+            TimeDelta::milliseconds(95)
+        );
+        assert_eq!(
+            stats.percentile(100.0).unwrap().delay(),
+            TimeDelta::milliseconds(100)
+        );
+    }
+}
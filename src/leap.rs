@@ -0,0 +1,641 @@
+//! Leap second handling modes, including the 24-hour linear leap smear used by Google and AWS NTP.
+
+use chrono::{DateTime, NaiveTime, TimeDelta, Timelike, Utc};
+
+use crate::{
+    clamp_to_i64, from_gps_nanoseconds, from_gpst, from_wide_gps_nanoseconds, num_leaps_utc,
+    signed_gps_nanoseconds_with, Clock, Gpst, GpstError, GpstLike, LeapSecond, TimeInput,
+    GPS_EPOCH, LEAP_SECONDS, LEAP_SECOND_TABLE, LEAP_TABLE_EXPIRES, TO_NANO_INT,
+};
+
+/// Length of a smear window in UTC nanoseconds, centred on the leap second (noon to noon UTC).
+const SMEAR_NANOSECONDS: i64 = 86400 * TO_NANO_INT;
+
+/// How leap seconds are applied when converting between UTC and GPS Time (GPST).
+///
+/// `bool` converts into this, so `true` selects [`LeapHandling::Step`] and `false` selects [`LeapHandling::None`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LeapHandling {
+    /// Ignore leap seconds, so UTC and GPST only differ by their epochs.
+    None,
+    /// Apply each leap second as a one second step at its insertion instant.
+    #[default]
+    Step,
+    /// UTC as read from a smeared clock, which spreads each leap second linearly over the 24 hours from noon UTC
+    /// before to noon UTC after the insertion instant.
+    Smear24h,
+}
+
+impl From<bool> for LeapHandling {
+    fn from(leap_seconds: bool) -> LeapHandling {
+        if leap_seconds {
+            LeapHandling::Step
+        } else {
+            LeapHandling::None
+        }
+    }
+}
+
+/// A UTC instant converted from GPS Time (GPST), distinguishing the inserted leap second from the one after it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum UtcInstant {
+    /// An instant with a unique UTC reading.
+    Unique(DateTime<Utc>),
+    /// An instant during an inserted leap second, read as `23:59:60`. chrono represents this as `23:59:59` with
+    /// nanoseconds of `1_000_000_000` or more.
+    DuringLeap(DateTime<Utc>),
+}
+
+impl UtcInstant {
+    /// The UTC date-time, in chrono's leap second representation for [`UtcInstant::DuringLeap`].
+    pub fn date_time(&self) -> DateTime<Utc> {
+        match self {
+            UtcInstant::Unique(date_time) | UtcInstant::DuringLeap(date_time) => *date_time,
+        }
+    }
+}
+
+impl Gpst {
+    /// Whether this epoch falls within an inserted leap second, i.e. UTC reads `23:59:60`. GPST data converted from
+    /// UTC without [`LeapHandling::Step`] never does, as that UTC reading has no such epoch.
+    pub fn is_during_leap_insertion(&self) -> bool {
+        inserted_second(self.stepped_nanoseconds()).is_some()
+    }
+
+    /// Nanoseconds since GPS Epoch on the leap second adjusted GPS scale, rebased from GPST data converted from UTC
+    /// with other leap handling. Untagged data is taken as leap second adjusted, as in [`check_leap_table`].
+    fn stepped_nanoseconds(&self) -> i128 {
+        let nanoseconds = self.wide_nanoseconds_since_epoch();
+        let utc_nanoseconds = match self.leap_handling.unwrap_or_default() {
+            LeapHandling::Step => return nanoseconds,
+            LeapHandling::None => nanoseconds,
+            LeapHandling::Smear24h => {
+                nanoseconds - smeared_leaps(clamp_to_i64(nanoseconds)) as i128
+            }
+        };
+        utc_nanoseconds + num_leaps_utc(clamp_to_i64(utc_nanoseconds)) as i128
+    }
+}
+
+/// Given weeks since GPS Epoch and week seconds, convert to UTC adjusted for leap seconds, without collapsing an
+/// inserted leap second onto the second before it as [`from_gpst`](crate::from_gpst) does.
+///
+/// Fails on the same invalid input as [`from_gpst`](crate::from_gpst).
+pub fn from_gpst_exact(week: i64, week_seconds: f64) -> Result<UtcInstant, GpstError> {
+    let nanoseconds = Gpst::new(week, week_seconds)?.wide_nanoseconds_since_epoch();
+    match inserted_second(nanoseconds) {
+        Some(start) => {
+            let before = from_gps_nanoseconds(start - TO_NANO_INT, true)?;
+            let nanosecond = (TO_NANO_INT as i128 + nanoseconds - start as i128) as u32;
+            DateTime::from_timestamp(before.timestamp(), nanosecond)
+                .map(UtcInstant::DuringLeap)
+                .ok_or(GpstError::TimestampNano(TimeInput::GpsNanoseconds(
+                    nanoseconds,
+                )))
+        }
+        None => from_wide_gps_nanoseconds(nanoseconds, true).map(UtcInstant::Unique),
+    }
+}
+
+/// Start of the inserted leap second containing GPS nanoseconds since GPS Epoch, if any.
+pub(crate) fn inserted_second(gps_nanoseconds: i128) -> Option<i64> {
+    LEAP_SECONDS
+        .iter()
+        .map(|leap_second| leap_second * TO_NANO_INT)
+        .find(|start| (*start as i128..(start + TO_NANO_INT) as i128).contains(&gps_nanoseconds))
+}
+
+/// Whether a UTC date-time reads `23:59:60` of an inserted leap second, given its (folded) UTC nanoseconds since GPS
+/// Epoch.
+pub(crate) fn is_inserted_utc(date_time: &DateTime<Utc>, utc_nanoseconds: i64) -> bool {
+    date_time.nanosecond() >= TO_NANO_INT as u32
+        && LEAP_SECONDS
+            .iter()
+            .enumerate()
+            .map(|(index, leap_second)| (leap_second - index as i64) * TO_NANO_INT)
+            .any(|start| (start..start + TO_NANO_INT).contains(&utc_nanoseconds))
+}
+
+/// An instant on either time scale, for leap second table queries.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LeapInstant {
+    /// A UTC instant.
+    Utc(DateTime<Utc>),
+    /// A GPS Time (GPST) epoch, rebased onto leap second adjusted GPST if it was converted from UTC with other leap
+    /// handling.
+    Gps(Gpst),
+}
+
+impl From<DateTime<Utc>> for LeapInstant {
+    fn from(date_time: DateTime<Utc>) -> LeapInstant {
+        LeapInstant::Utc(date_time)
+    }
+}
+
+impl From<Gpst> for LeapInstant {
+    fn from(gpst: Gpst) -> LeapInstant {
+        LeapInstant::Gps(gpst)
+    }
+}
+
+impl LeapInstant {
+    /// Number of leap seconds in effect. A new leap second takes effect at `00:00:00` UTC after its insertion.
+    fn leaps_in_effect(&self) -> usize {
+        match self {
+            LeapInstant::Utc(date_time) => {
+                let seconds = date_time.timestamp() - GPS_EPOCH / TO_NANO_INT;
+                LEAP_SECONDS
+                    .iter()
+                    .enumerate()
+                    .take_while(|(index, leap_second)| *leap_second - *index as i64 <= seconds)
+                    .count()
+            }
+            LeapInstant::Gps(gpst) => {
+                let nanoseconds = gpst.stepped_nanoseconds();
+                LEAP_SECONDS
+                    .iter()
+                    .take_while(|leap_second| {
+                        ((*leap_second + 1) * TO_NANO_INT) as i128 <= nanoseconds
+                    })
+                    .count()
+            }
+        }
+    }
+}
+
+/// GPS−UTC offset in whole seconds in effect at an instant given as a `DateTime<Utc>` or [`Gpst`].
+pub fn gps_utc_offset_at(instant: impl Into<LeapInstant>) -> i64 {
+    instant.into().leaps_in_effect() as i64
+}
+
+/// Check that the builtin leap second table is known complete at an instant, failing with
+/// [`GpstError::LeapTableExpired`] from [`LEAP_TABLE_EXPIRES`](crate::LEAP_TABLE_EXPIRES) on, when a leap second
+/// announced since could be missing. A [`Gpst`] is converted to UTC with its own leap second handling, or
+/// [`LeapHandling::Step`] if unknown.
+pub fn check_leap_table(instant: impl Into<LeapInstant>) -> Result<(), GpstError> {
+    let instant = match instant.into() {
+        LeapInstant::Utc(date_time) => date_time,
+        LeapInstant::Gps(gpst) => from_wide_gps_nanoseconds(
+            gpst.wide_nanoseconds_since_epoch(),
+            gpst.leap_handling.unwrap_or_default(),
+        )?,
+    };
+    let expires = LEAP_TABLE_EXPIRES.and_time(NaiveTime::MIN).and_utc();
+    if instant >= expires {
+        return Err(GpstError::LeapTableExpired { instant, expires });
+    }
+    Ok(())
+}
+
+/// How far the leap seconds applied by a conversion can be trusted, see [`gpst_with_confidence`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LeapConfidence {
+    /// The instant is before [`LEAP_TABLE_EXPIRES`](crate::LEAP_TABLE_EXPIRES), so the builtin table is complete for
+    /// it, or no leap seconds were applied.
+    Known,
+    /// The instant is past the table's expiry, e.g. a planned future epoch, so the last known GPS−UTC offset is
+    /// assumed to still hold. Any leap second announced for it is not yet known to anyone.
+    Assumed,
+    /// The table expired before the present, so a leap second announced since may be missing, and the applied
+    /// offset may already be wrong. The crate needs updating.
+    Expired,
+}
+
+impl LeapConfidence {
+    /// Confidence in the builtin table at an instant, given the present time `now`.
+    pub fn at(instant: impl Into<LeapInstant>, now: DateTime<Utc>) -> LeapConfidence {
+        if check_leap_table(instant).is_ok() {
+            LeapConfidence::Known
+        } else if check_leap_table(now).is_ok() {
+            LeapConfidence::Assumed
+        } else {
+            LeapConfidence::Expired
+        }
+    }
+}
+
+/// Convert a DateTime<Utc> to GPS Time (GPST) as [`GpstLike::gpst`] does, also reporting whether the leap seconds
+/// applied are known or assumed, as of the time from `clock`.
+pub fn gpst_with_confidence(
+    date_time: &DateTime<Utc>,
+    leap_seconds: impl Into<LeapHandling>,
+    clock: &impl Clock,
+) -> Result<(Gpst, LeapConfidence), GpstError> {
+    let leap_seconds = leap_seconds.into();
+    let gpst = date_time.gpst(leap_seconds)?;
+    Ok((gpst, confidence(*date_time, leap_seconds, clock)))
+}
+
+/// Convert GPS Time (GPST) to a DateTime<Utc> as [`from_gpst`] does, also reporting whether the leap seconds applied
+/// are known or assumed, as of the time from `clock`.
+pub fn from_gpst_with_confidence(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+    clock: &impl Clock,
+) -> Result<(DateTime<Utc>, LeapConfidence), GpstError> {
+    let leap_seconds = leap_seconds.into();
+    let date_time = from_gpst(week, week_seconds, leap_seconds)?;
+    Ok((date_time, confidence(date_time, leap_seconds, clock)))
+}
+
+fn confidence(
+    date_time: DateTime<Utc>,
+    leap_seconds: LeapHandling,
+    clock: &impl Clock,
+) -> LeapConfidence {
+    match leap_seconds {
+        LeapHandling::None => LeapConfidence::Known,
+        LeapHandling::Step | LeapHandling::Smear24h => LeapConfidence::at(date_time, clock.now()),
+    }
+}
+
+/// UTC instant at which the latest leap second at or before an instant took effect, if any.
+pub fn previous_leap(instant: impl Into<LeapInstant>) -> Option<DateTime<Utc>> {
+    instant
+        .into()
+        .leaps_in_effect()
+        .checked_sub(1)
+        .map(leap_effective)
+}
+
+/// UTC instant at which the next leap second after an instant takes effect, if one is known.
+pub fn next_leap(instant: impl Into<LeapInstant>) -> Option<DateTime<Utc>> {
+    let index = instant.into().leaps_in_effect();
+    (index < LEAP_SECONDS.len()).then(|| leap_effective(index))
+}
+
+/// Leap seconds taking effect after `start` and up to `end`, given as `DateTime<Utc>` or [`Gpst`], in chronological
+/// order: exactly those where the GPS−UTC offset at `end` differs from the offset at `start`. Each yields its
+/// effective UTC date, the GPS seconds at the start of the inserted second and the new GPS−UTC offset. Empty if
+/// `end` is before `start`.
+pub fn leap_events_between(
+    start: impl Into<LeapInstant>,
+    end: impl Into<LeapInstant>,
+) -> impl Iterator<Item = LeapSecond> {
+    let first = start.into().leaps_in_effect();
+    let last = end.into().leaps_in_effect().max(first);
+    LEAP_SECOND_TABLE[first..last].iter().copied()
+}
+
+/// Physical time elapsed from `start` to `end`, including the leap seconds inserted between them, negative if `end`
+/// is earlier. `end - start` in chrono counts every UTC day as 86400 seconds, so it drops inserted leap seconds.
+///
+/// Both instants are measured on the GPS time scale, so `23:59:60` readings count within their inserted second and
+/// instants before GPS Epoch are supported.
+pub fn gps_elapsed(start: DateTime<Utc>, end: DateTime<Utc>) -> TimeDelta {
+    let gps_nanoseconds = |date_time: &DateTime<Utc>| {
+        signed_gps_nanoseconds_with(date_time, LeapHandling::Step, num_leaps_utc)
+    };
+    let nanoseconds = gps_nanoseconds(&end) - gps_nanoseconds(&start);
+    let seconds = nanoseconds.div_euclid(TO_NANO_INT as i128) as i64;
+    TimeDelta::seconds(seconds)
+        + TimeDelta::nanoseconds(nanoseconds.rem_euclid(TO_NANO_INT as i128) as i64)
+}
+
+/// UTC instant (`00:00:00` after the insertion) at which a leap second from the table takes effect.
+fn leap_effective(index: usize) -> DateTime<Utc> {
+    LEAP_SECOND_TABLE[index]
+        .effective
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+/// Leap nanoseconds to add to (smeared) UTC nanoseconds since GPS Epoch.
+pub(crate) fn smeared_leaps_utc(utc_nanoseconds: i64) -> i64 {
+    let mut count = 0;
+    for (index, leap_second) in LEAP_SECONDS.iter().enumerate() {
+        let start = (leap_second - index as i64) * TO_NANO_INT - SMEAR_NANOSECONDS / 2;
+        count += smear_fraction(utc_nanoseconds - start, SMEAR_NANOSECONDS);
+    }
+    count
+}
+
+/// Leap nanoseconds to subtract from GPS nanoseconds since GPS Epoch to get smeared UTC.
+pub(crate) fn smeared_leaps(gps_nanoseconds: i64) -> i64 {
+    let mut count = 0;
+    for leap_second in LEAP_SECONDS {
+        // On the GPS scale, the window starts after the preceding leaps and lasts one second longer.
+        let start = leap_second * TO_NANO_INT - SMEAR_NANOSECONDS / 2;
+        count += smear_fraction(gps_nanoseconds - start, SMEAR_NANOSECONDS + TO_NANO_INT);
+    }
+    count
+}
+
+/// Nanoseconds of one leap second applied `elapsed` nanoseconds into a smear window `length` nanoseconds long.
+fn smear_fraction(elapsed: i64, length: i64) -> i64 {
+    if elapsed <= 0 {
+        0
+    } else if elapsed >= length {
+        TO_NANO_INT
+    } else {
+        (elapsed as i128 * TO_NANO_INT as i128 / length as i128) as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        check_leap_table, from_gpst, from_gpst_exact, from_gpst_with_confidence, gps_elapsed,
+        gps_utc_offset_at, gpst_with_confidence, leap_events_between, next_leap, previous_leap,
+        Clock, Gpst, GpstError, GpstLike, LeapConfidence, LeapHandling, UtcInstant,
+        LEAP_TABLE_EXPIRES,
+    };
+    use chrono::{Datelike, NaiveDate, TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn confidence() {
+        struct Fixed(chrono::DateTime<Utc>);
+        impl Clock for Fixed {
+            fn now(&self) -> chrono::DateTime<Utc> {
+                self.0
+            }
+        }
+        let expires = LEAP_TABLE_EXPIRES.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let before = Fixed(expires - TimeDelta::days(30));
+        let after = Fixed(expires + TimeDelta::days(30));
+        let past = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let future = Utc.with_ymd_and_hms(2040, 1, 1, 0, 0, 0).unwrap();
+
+        let (gpst, confidence) = gpst_with_confidence(&past, true, &after).unwrap();
+        assert_eq!(gpst, past.gpst(true).unwrap());
+        assert_eq!(confidence, LeapConfidence::Known);
+        let (_, confidence) = gpst_with_confidence(&future, true, &before).unwrap();
+        assert_eq!(confidence, LeapConfidence::Assumed);
+        let (_, confidence) = gpst_with_confidence(&future, true, &after).unwrap();
+        assert_eq!(confidence, LeapConfidence::Expired);
+        let (_, confidence) = gpst_with_confidence(&future, false, &after).unwrap();
+        assert_eq!(confidence, LeapConfidence::Known);
+
+        let gpst = future.gpst(true).unwrap();
+        let (date_time, confidence) =
+            from_gpst_with_confidence(gpst.week, gpst.week_seconds, true, &before).unwrap();
+        assert_eq!((date_time, confidence), (future, LeapConfidence::Assumed));
+        assert_eq!(
+            LeapConfidence::at(gpst, after.now()),
+            LeapConfidence::Expired
+        );
+    }
+
+    #[test]
+    fn leap_table_expiry() {
+        let expires = LEAP_TABLE_EXPIRES.and_hms_opt(0, 0, 0).unwrap().and_utc();
+        let before = expires - TimeDelta::nanoseconds(1);
+        assert!(check_leap_table(before).is_ok());
+        assert!(check_leap_table(before.gpst(true).unwrap()).is_ok());
+        assert_eq!(
+            check_leap_table(expires),
+            Err(GpstError::LeapTableExpired {
+                instant: expires,
+                expires
+            })
+        );
+        assert!(check_leap_table(expires.gpst(true).unwrap()).is_err());
+        // Converted back with its own handling, not a leap adjusted reading 18 seconds earlier.
+        assert!(check_leap_table(expires.gpst(false).unwrap()).is_err());
+        assert!(check_leap_table(before.gpst(false).unwrap()).is_ok());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let late = Gpst::normalized(16696, 45.5);
+        assert!(!late.is_during_leap_insertion());
+        assert_eq!(gps_utc_offset_at(late), 18);
+        assert_eq!(
+            from_gpst_exact(16696, 45.5).unwrap(),
+            UtcInstant::Unique(
+                NaiveDate::from_ymd_opt(2299, 12, 31)
+                    .unwrap()
+                    .and_hms_milli_opt(0, 0, 27, 500)
+                    .unwrap()
+                    .and_utc()
+            )
+        );
+        assert!(matches!(
+            check_leap_table(late),
+            Err(GpstError::LeapTableExpired { instant, .. }) if instant.year() == 2299
+        ));
+    }
+
+    #[test]
+    fn exact_invalid_input() {
+        assert!(matches!(
+            from_gpst_exact(1930, f64::NAN),
+            Err(GpstError::NonFiniteInput(_))
+        ));
+        assert!(matches!(
+            from_gpst_exact(1930, 604800.0),
+            Err(GpstError::TowOutOfRange { .. })
+        ));
+        assert!(matches!(
+            from_gpst_exact(-1, 17.5),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+    }
+
+    #[test]
+    fn tagged_gpst() {
+        // GPST data converted with any leap handling queries the table at the same instant as its UTC source.
+        for (date_time, offset) in [
+            (Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap(), 17),
+            (Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 5).unwrap(), 18),
+        ] {
+            for leap_seconds in [
+                LeapHandling::None,
+                LeapHandling::Step,
+                LeapHandling::Smear24h,
+            ] {
+                let gpst = date_time.gpst(leap_seconds).unwrap();
+                assert_eq!(gps_utc_offset_at(gpst), offset);
+                assert_eq!(previous_leap(gpst), previous_leap(date_time));
+                assert_eq!(next_leap(gpst), next_leap(date_time));
+                assert_eq!(
+                    leap_events_between(gpst, date_time).count(),
+                    0,
+                    "{leap_seconds:?}"
+                );
+            }
+        }
+
+        // Without leap seconds, 1930 17.5 reads 2017-01-01T00:00:17.5 UTC, well after the insertion.
+        let raw = NaiveDate::from_ymd_opt(2017, 1, 1)
+            .unwrap()
+            .and_hms_milli_opt(0, 0, 17, 500)
+            .unwrap()
+            .and_utc()
+            .gpst(false)
+            .unwrap();
+        assert_eq!((raw.week, raw.week_seconds), (1930, 17.5));
+        assert!(!raw.is_during_leap_insertion());
+        assert!(Gpst::normalized(1930, 17.5).is_during_leap_insertion());
+    }
+
+    #[test]
+    fn from_bool() {
+        assert_eq!(LeapHandling::from(true), LeapHandling::Step);
+        assert_eq!(LeapHandling::from(false), LeapHandling::None);
+    }
+
+    #[test]
+    fn insertion() {
+        // 2016-12-31T23:59:60 UTC is 1930 17 GPST, and 2017-01-01T00:00:00 UTC is one second later.
+        let leap = Gpst::normalized(1930, 17.5);
+        assert!(leap.is_during_leap_insertion());
+        assert!(!Gpst::normalized(1930, 18.0).is_during_leap_insertion());
+        assert!(!Gpst::normalized(1930, 16.5).is_during_leap_insertion());
+
+        let reading = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        assert_eq!(
+            from_gpst_exact(1930, 17.5).unwrap(),
+            UtcInstant::DuringLeap(reading)
+        );
+        assert_eq!(reading.to_rfc3339(), "2016-12-31T23:59:60.500+00:00");
+        let gpst = reading.gpst(true).unwrap();
+        assert_eq!(
+            (gpst.week, gpst.week_seconds),
+            (leap.week, leap.week_seconds)
+        );
+
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            from_gpst_exact(1930, 18.0).unwrap(),
+            UtcInstant::Unique(after)
+        );
+        // The lossy conversion collapses the inserted second onto the one before it.
+        assert_eq!(
+            from_gpst(1930, 17.5, true).unwrap(),
+            after - TimeDelta::milliseconds(500)
+        );
+    }
+
+    #[test]
+    fn offset_queries() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        assert_eq!(gps_utc_offset_at(date_time), 13);
+        assert_eq!(
+            previous_leap(date_time),
+            Some(Utc.with_ymd_and_hms(1999, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            next_leap(date_time),
+            Some(Utc.with_ymd_and_hms(2006, 1, 1, 0, 0, 0).unwrap())
+        );
+
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(gps_utc_offset_at(after), 18);
+        assert_eq!(gps_utc_offset_at(after - TimeDelta::nanoseconds(1)), 17);
+        assert_eq!(previous_leap(after), Some(after));
+        assert_eq!(next_leap(after), None);
+
+        // The inserted second 2016-12-31T23:59:60 still has the old offset.
+        assert_eq!(gps_utc_offset_at(Gpst::normalized(1930, 17.5)), 17);
+        assert_eq!(gps_utc_offset_at(Gpst::normalized(1930, 18.0)), 18);
+        assert_eq!(gps_utc_offset_at(Gpst::normalized(0, 0.0)), 0);
+        assert_eq!(previous_leap(Gpst::normalized(0, 0.0)), None);
+    }
+
+    #[test]
+    fn leap_events() {
+        let start = Utc.with_ymd_and_hms(2012, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let events: Vec<_> = leap_events_between(start, end).collect();
+        let dates: Vec<_> = events.iter().map(|event| event.effective).collect();
+        assert_eq!(
+            dates,
+            [
+                NaiveDate::from_ymd_opt(2012, 7, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2015, 7, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2017, 1, 1).unwrap(),
+            ]
+        );
+        assert_eq!(events[2].gps_utc_offset, 18);
+        assert_eq!(events[2].gps_seconds, 1167264017);
+
+        // The same window on the GPS scale, which ends one second later.
+        let gps_start = start.gpst(true).unwrap();
+        let gps_end = end.gpst(true).unwrap();
+        assert_eq!(leap_events_between(gps_start, gps_end).count(), 3);
+        let before_end = Gpst::normalized(gps_end.week, gps_end.week_seconds - 1.0);
+        assert_eq!(leap_events_between(gps_start, before_end).count(), 2);
+
+        assert_eq!(leap_events_between(end, start).count(), 0);
+        assert_eq!(
+            leap_events_between(end, end + TimeDelta::days(365)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn elapsed() {
+        let start = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 59).unwrap();
+        let end = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(end - start, TimeDelta::seconds(1));
+        assert_eq!(gps_elapsed(start, end), TimeDelta::seconds(2));
+        assert_eq!(gps_elapsed(end, start), TimeDelta::seconds(-2));
+
+        // Half way through the inserted second.
+        let inserted = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        assert_eq!(gps_elapsed(inserted, end), TimeDelta::milliseconds(500));
+
+        // 18 leap seconds since GPS Epoch, and none across the epoch itself.
+        let epoch = Utc.with_ymd_and_hms(1980, 1, 6, 0, 0, 0).unwrap();
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            gps_elapsed(epoch, now),
+            now - epoch + TimeDelta::seconds(18)
+        );
+        let before = epoch - TimeDelta::days(1);
+        assert_eq!(gps_elapsed(before, epoch), TimeDelta::days(1));
+    }
+
+    #[test]
+    fn smear() {
+        // The 2016-12-31 leap second, with a window from 2016-12-31T12:00 to 2017-01-01T12:00 UTC.
+        let midnight = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let step = midnight.gpst(LeapHandling::Step).unwrap();
+        let smear = midnight.gpst(LeapHandling::Smear24h).unwrap();
+        assert_eq!(step.week_seconds - smear.week_seconds, 0.5);
+
+        for hours in [-13, 13] {
+            let date_time = midnight + TimeDelta::hours(hours);
+            assert_eq!(
+                date_time
+                    .gpst(LeapHandling::Smear24h)
+                    .unwrap()
+                    .with_leap_handling(LeapHandling::Step),
+                date_time.gpst(LeapHandling::Step).unwrap()
+            );
+        }
+
+        let morning = midnight + TimeDelta::hours(6);
+        let gpst = morning.gpst(LeapHandling::Smear24h).unwrap();
+        let unadjusted = morning.gpst(false).unwrap();
+        assert_eq!(gpst.week_seconds - unadjusted.week_seconds, 17.75);
+        assert_eq!(
+            gpst.to_utc_string(3, LeapHandling::Smear24h).unwrap(),
+            "2017-01-01T06:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn smear_monotonic() {
+        let start = Utc.with_ymd_and_hms(2016, 12, 31, 11, 0, 0).unwrap();
+        let mut last = Gpst::normalized(0, 0.0);
+        for minutes in 0..26 * 60 {
+            let gpst = (start + TimeDelta::minutes(minutes))
+                .gpst(LeapHandling::Smear24h)
+                .unwrap();
+            assert!(gpst.week_seconds > last.week_seconds || gpst.week > last.week);
+            last = gpst;
+        }
+    }
+}
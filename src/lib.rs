@@ -16,15 +16,43 @@ let date_time = chrono::NaiveDate::from_ymd_opt(2005, 1, 28)
 let gpst_time = date_time.gpst(true).unwrap();
 /***
  *  Seconds since GPS Epoch, Weeks since GPS Epoch, Seconds elapsed in week. Adjusted for leap seconds.
- *  Gpst { seconds: 790954213, week: 1307, week_seconds: 480613 }
+ *  Gpst { seconds: 790954213.0, week: 1307, week_seconds: 480613.0 }
  ***/
-let date_time = from_gpst(1307, 480613, true).unwrap();
+let date_time = from_gpst(1307, 480613.0, true).unwrap();
 /***
  *  GPST is always UTC (with drift for leap seconds, so enable that flag if needed), so we return a DateTime<Utc>.
  *  2005-01-28T13:30:00Z
  ***/
 ```
 
+## Leap second handling
+The leap second flag also accepts a [`LeapHandling`]: `None` (same as `false`), `Step` (same as `true`) or `Smear24h`,
+for timestamps from NTP servers that smear leap seconds linearly over 24 hours (e.g. Google and AWS).
+
+## Optional features
+- `std` (default): `Gpst::now()` from the system clock, and `LeapTable::load_system()` to convert with the system
+  tzdata leap second table.
+- `time`: conversions for [`time::OffsetDateTime`](https://docs.rs/time/latest/time/struct.OffsetDateTime.html).
+- `hifitime`: conversions between [`hifitime::Epoch`](https://docs.rs/hifitime/latest/hifitime/struct.Epoch.html) and `Gpst` (`From<Epoch>`, `TryFrom<Gpst>`), and a test suite cross-checking leap second handling against hifitime.
+- `hifitime-oracle` (dev only): `check_against_hifitime()` differential oracle, fuzzed over random epochs in the test suite.
+- `rayon`: `par_gpst_batch()` and `par_from_gpst_batch()`, parallel versions of the batch conversions.
+- `arbitrary`: [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html) for `Gpst`, generating valid epochs for fuzzing.
+- `proptest`: `gpst_strategy()`, a [proptest](https://docs.rs/proptest/latest/proptest/) strategy of valid `Gpst` epochs.
+- `cli`: a `gpst` binary converting between RFC 3339 UTC, GPS seconds and week/TOW, e.g. `gpst 1307 480613` or a
+  file of epochs on stdin, with `--leap`/`--no-leap`. Install with `cargo install chrono_gpst --features cli`.
+- `ffi`: a C interface (`gpst_from_unix()`, `gpst_to_unix()`, `gpst_from_week_tow()` and status codes), declared in
+  `include/chrono_gpst.h`. Build the C library with `cargo rustc --release --lib --features ffi --crate-type cdylib`
+  (or `staticlib`); plain builds only produce the rlib.
+- `python`: Python bindings `to_gpst(datetime, leap=True)` and `from_gpst(week, tow, leap=True)`, interoperating with
+  `datetime.datetime`. Build with `maturin develop --features python,pyo3/extension-module`.
+- `wasm`: [wasm-bindgen](https://docs.rs/wasm-bindgen/latest/wasm_bindgen/) exports `gpstFromDate()`, `gpstToDate()`,
+  `gpstFromUnixMillis()` and `gpstToUnixMillis()`, with `{ week, tow }` objects, for browser tooling.
+- `decimal`: exact [`rust_decimal::Decimal`](https://docs.rs/rust_decimal/latest/rust_decimal/struct.Decimal.html)
+  GPS seconds and time of week, `gpst_seconds_decimal()`, `from_gpst_decimal()` and friends.
+- `serde`: `Serialize`/`Deserialize` for `Gpst` and `LeapHandling`, using the compact `Gpst::to_bytes()` layout for
+  binary formats such as bincode or postcard.
+- `verify-leap-table`: fail the build if the builtin leap second table drifts from the vendored `data/leap-seconds.list`.
+
 ## Acknowledgements
 Adapted from PHP algorithm here: [https://www.andrews.edu/~tzs/timeconv/timealgorithm.html](https://www.andrews.edu/~tzs/timeconv/timealgorithm.html).
 
@@ -32,30 +60,367 @@ Adapted from PHP algorithm here: [https://www.andrews.edu/~tzs/timeconv/timealgo
 Leap seconds could be added in the future, in which a new version of this crate would need to be replaced.
 */
 
-use chrono::{DateTime, Utc};
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::ops::Range;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Timelike, Utc};
 use thiserror::Error;
 
+mod adjust;
+mod approx;
+mod batch;
+mod builder;
+mod bytes;
+mod calendar;
+mod clock;
+pub mod convert;
+mod converter;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod dual;
+mod epochs;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "arbitrary", feature = "proptest"))]
+mod fuzz;
+mod glonass;
+mod grid;
+mod group;
+#[cfg(feature = "hifitime")]
+mod hifitime_interop;
+mod key;
+mod latency;
+mod leap;
+mod monotonic;
+mod nav;
+pub mod nmea;
+#[cfg(feature = "hifitime-oracle")]
+mod oracle;
+#[cfg(feature = "python")]
+mod python;
+mod range;
+mod resample;
+mod rollover;
+mod round;
+pub mod rtcm;
+mod scale;
+pub mod sp3;
+mod stream;
+mod summary;
+#[cfg(feature = "time")]
+mod time_interop;
+mod tz;
+#[cfg(feature = "std")]
+mod tzdata;
+mod ublox;
+mod unix;
+mod ut1;
+mod utc_parameters;
+pub mod vendors;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use approx::approx_eq_utc;
+use batch::LeapCursor;
+pub use batch::{from_gpst_batch, gpst_batch};
+#[cfg(feature = "rayon")]
+pub use batch::{par_from_gpst_batch, par_gpst_batch};
+pub use builder::GpstBuilder;
+pub use calendar::{
+    date_from_gps_week_day, first_gps_week_of_year, gps_seconds_from_week_tow, gps_week_and_day,
+    gps_week_of, weeks_in_year, GpsDay, GpsSeconds, GpsWeek,
+};
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use converter::{Converter, TimeConverter};
+#[cfg(feature = "decimal")]
+pub use decimal::{
+    from_gpst_decimal, from_gpst_seconds_decimal, gpst_seconds_decimal, gpst_week_tow_decimal,
+};
+pub use dual::DualTime;
+pub use epochs::GpstEpochs;
+#[cfg(feature = "proptest")]
+pub use fuzz::gpst_strategy;
+pub use glonass::GlonassDayTime;
+pub use grid::snap_to_grid;
+pub use group::{group_by_gps_day, group_by_gps_week};
+pub use latency::{Latency, LatencyClass, LatencyStats, LatencyThresholds};
+pub use leap::{
+    check_leap_table, from_gpst_exact, from_gpst_with_confidence, gps_elapsed, gps_utc_offset_at,
+    gpst_with_confidence, leap_events_between, next_leap, previous_leap, LeapConfidence,
+    LeapHandling, LeapInstant, UtcInstant,
+};
+use leap::{inserted_second, is_inserted_utc, smeared_leaps, smeared_leaps_utc};
+pub use monotonic::{MonotonicGuard, MonotonicPolicy};
+pub use nav::{
+    apply_sv_clock, resolve_ms_ambiguity, time_from_reference, transmission_time, SPEED_OF_LIGHT,
+};
+#[cfg(feature = "hifitime-oracle")]
+pub use oracle::{check_against_hifitime, OracleMismatch, ORACLE_TOLERANCE_NANOSECONDS};
+pub use range::GpstRange;
+pub use resample::{resample, GapPolicy, ResampleMethod};
+pub use rollover::{
+    from_truncated_week, resolve_truncated_week, WeekWidth, BEIDOU_EPOCH_WEEK, GALILEO_EPOCH_WEEK,
+};
+pub use round::{from_gpst_rounded, from_gpst_seconds_rounded, Round};
+pub use scale::{SystemTimeOffset, SystemTimeOffsets, TimeScale};
+pub use stream::{FromGpst, GpstIteratorExt, ToGpst};
+pub use summary::WeekSummary;
+#[cfg(feature = "time")]
+pub use time_interop::{offset_date_time_from_gpst, offset_date_time_from_gpst_seconds};
+pub use tz::{GpsOffset, GpsTime};
+#[cfg(feature = "std")]
+pub use tzdata::{LeapTable, LeapTableIssue};
+pub use unix::{gpst_to_unix, unix_to_gpst};
+pub use ut1::{Dut1Table, Ut1Provider};
+pub use utc_parameters::UtcParameters;
+
+/// The input a conversion failed on, carried by [`GpstError`] so callers can inspect it without parsing messages.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TimeInput {
+    /// A UTC instant.
+    Utc(DateTime<Utc>),
+    /// Nanoseconds since the Unix epoch, e.g. from another date-time crate.
+    UnixNanoseconds(i128),
+    /// A GPS week.
+    Week(i64),
+    /// A GPS week and seconds of week.
+    WeekSeconds(i64, f64),
+    /// Seconds since GPS Epoch.
+    GpsSeconds(f64),
+    /// Nanoseconds since GPS Epoch.
+    GpsNanoseconds(i128),
+    /// GPST data moved by a number of seconds.
+    Shifted(Gpst, f64),
+}
+
+impl fmt::Display for TimeInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeInput::Utc(date_time) => write_rfc3339(f, date_time),
+            TimeInput::UnixNanoseconds(nanoseconds) => write!(f, "{nanoseconds} Unix nanoseconds"),
+            TimeInput::Week(week) => write!(f, "GPS week {week}"),
+            TimeInput::WeekSeconds(week, week_seconds) => {
+                write!(f, "GPS week {week}, {week_seconds} seconds")
+            }
+            TimeInput::GpsSeconds(seconds) => write!(f, "{seconds} GPS seconds"),
+            TimeInput::GpsNanoseconds(nanoseconds) => write!(f, "{nanoseconds} GPS nanoseconds"),
+            TimeInput::Shifted(gpst, seconds) => write!(f, "{gpst} + {seconds} seconds"),
+        }
+    }
+}
+
+/// Write a UTC instant as [`DateTime::to_rfc3339`] does, without allocating.
+fn write_rfc3339(f: &mut fmt::Formatter<'_>, date_time: &DateTime<Utc>) -> fmt::Result {
+    let year = date_time.year();
+    if (0..=9999).contains(&year) {
+        write!(f, "{year:04}")?;
+    } else {
+        write!(f, "{year:+05}")?;
+    }
+    // chrono carries a leap second as nanoseconds past one second.
+    let (second, nanoseconds) = match date_time.nanosecond() {
+        nanoseconds if nanoseconds >= TO_NANO_INT as u32 => (60, nanoseconds - TO_NANO_INT as u32),
+        nanoseconds => (date_time.second(), nanoseconds),
+    };
+    write!(
+        f,
+        "-{:02}-{:02}T{:02}:{:02}:{second:02}",
+        date_time.month(),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute()
+    )?;
+    match nanoseconds {
+        0 => {}
+        _ if nanoseconds % 1000000 == 0 => write!(f, ".{:03}", nanoseconds / 1000000)?,
+        _ if nanoseconds % 1000 == 0 => write!(f, ".{:06}", nanoseconds / 1000)?,
+        _ => write!(f, ".{nanoseconds:09}")?,
+    }
+    f.write_str("+00:00")
+}
+
+/// The text a parse failed on, carried by [`GpstError::Parse`] as what was being parsed and where, rather than a
+/// copy of the text, so errors never allocate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParseInput {
+    /// What was being parsed, e.g. `"NMEA sentence"` or `"SP3 epoch"`.
+    pub record: &'static str,
+    /// Line number in a multi-line input, counting from 1.
+    pub line: Option<usize>,
+}
+
+impl ParseInput {
+    /// A single-line input.
+    pub(crate) const fn new(record: &'static str) -> ParseInput {
+        ParseInput { record, line: None }
+    }
+
+    /// A line of a multi-line input.
+    pub(crate) const fn at_line(record: &'static str, line: usize) -> ParseInput {
+        ParseInput {
+            record,
+            line: Some(line),
+        }
+    }
+}
+
+impl fmt::Display for ParseInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{} on line {line}", self.record),
+            None => f.write_str(self.record),
+        }
+    }
+}
+
 /// Custom errors
-#[derive(Error, Debug)]
+///
+/// Variants carry the offending input and, where there is one, the valid range, so callers can branch on them. They
+/// hold plain copyable data and format only when displayed, so failing conversions never allocate.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum GpstError {
     /// Error caused when provided date is earlier than GPS Epoch.
     #[error("Invalid date-time for GPST, is earlier than GPS Epoch: {0}")]
-    BeforeGPSEpoch(String),
+    BeforeGPSEpoch(TimeInput),
     /// Error caused when provided date is not within parseable bounds.
     /// [https://docs.rs/chrono/latest/chrono/struct.DateTime.html#method.timestamp_nanos_opt](https://docs.rs/chrono/latest/chrono/struct.DateTime.html#method.timestamp_nanos_opt)
     #[error("Could not convert date-time to nanosecond timestamp: {0}")]
-    TimestampNano(String),
+    TimestampNano(TimeInput),
+    /// Error caused when a truncated week number does not fit in its bit width.
+    #[error("Invalid truncated week number: {0}")]
+    TruncatedWeek(u16),
+    /// Error caused when a sampling interval is zero or negative.
+    #[error("Invalid interval, must be positive: {0}")]
+    InvalidInterval(TimeDelta),
+    /// Error caused when a Z-count exceeds 29 bits or its time of week count exceeds one week.
+    #[error("Invalid Z-count: {0}")]
+    ZCount(u32),
+    /// Error caused when an almanac reference time count exceeds 147 (602112 seconds).
+    #[error("Invalid almanac reference time: {0}")]
+    AlmanacToa(u8),
+    /// Error caused when a leap second day number (DN) is not within 1 (Sunday) to 7.
+    #[error("Invalid leap second day number: {0}")]
+    LeapDayNumber(u8),
+    /// Error caused when a HOW time of week count exceeds one week.
+    #[error("Invalid time of week count: {0}")]
+    TowCount(u32),
+    /// Error caused when a u-blox iTOW exceeds one week of milliseconds.
+    #[error("Invalid iTOW: {0} ms")]
+    Itow(u32),
+    /// Error caused when a day of week is not within 0 (Sunday) to 6 (Saturday), or 7 where a field reserves it for an
+    /// unknown day.
+    #[error("Invalid day of week: {0}")]
+    DayOfWeek(u8),
+    /// Error caused when seconds of day are not within one day.
+    #[error("Invalid seconds of day: {0}")]
+    SecondsOfDay(f64),
+    /// Error caused when a day of year does not exist in its year.
+    #[error("Invalid day of year: {0}/{1}")]
+    DayOfYear(i32, u32),
+    /// Error caused when a nanosecond remainder is not less than one second.
+    #[error("Invalid nanoseconds, must be less than one second: {0}")]
+    Nanoseconds(u32),
+    /// Error caused when a sub-millisecond time of week is not within one millisecond.
+    #[error("Invalid sub-millisecond time of week: {0}")]
+    SubMillisecond(f64),
+    /// Error caused when a sortable integer key's time of week bits exceed one week.
+    #[error("Invalid sortable key: {0:#018x}")]
+    SortableKey(u64),
+    /// Error caused when an encoded GPST's time of week exceeds one week or reserved flag bits are set.
+    #[error("Invalid GPST bytes: {0:02x?}")]
+    InvalidBytes([u8; Gpst::ENCODED_LEN]),
+    /// Error caused when a text record cannot be parsed.
+    #[error("Could not parse {0}")]
+    Parse(ParseInput),
+    /// Error caused when an epoch steps backwards from the previous one.
+    #[error("Non-monotonic epoch {1} after {0}")]
+    NonMonotonic(Gpst, Gpst),
+    /// Error caused when an RTCM epoch time field is out of range.
+    #[error("Invalid RTCM epoch time: {0}")]
+    RtcmEpoch(u32),
+    /// Error caused when a GLONASS four-year interval number (N4) or day number within it (NT) is out of range.
+    #[error("Invalid GLONASS day: N4 {n4}, NT {nt}")]
+    GlonassDay {
+        /// Four-year interval number.
+        n4: u8,
+        /// Day number within the interval.
+        nt: u16,
+    },
+    /// Error caused when a proprietary receiver time tag is invalid.
+    #[error("Invalid {vendor} receiver time tag: {input}")]
+    TimeTag {
+        /// Receiver vendor.
+        vendor: &'static str,
+        /// The time tag.
+        input: TimeInput,
+    },
+    /// Error caused when arithmetic or a conversion overflows, e.g. on garbage week numbers.
+    #[error("Arithmetic overflow: {0}")]
+    Overflow(TimeInput),
+    /// Error caused when a conversion falls outside of the range chrono can represent.
+    #[error("Out of range for GPST conversion: {0}")]
+    OutOfRange(TimeInput),
+    /// Error caused when a time of week is not within its valid range, e.g. `0..604800` seconds.
+    #[error("Invalid time of week, must be within {valid:?}: {tow}")]
+    TowOutOfRange {
+        /// The time of week in seconds.
+        tow: f64,
+        /// The valid range in seconds.
+        valid: Range<f64>,
+    },
+    /// Error caused when a [`GpstBuilder`] is missing inputs or given conflicting ones.
+    #[error("Invalid GPST builder: {0}")]
+    Builder(&'static str),
+    /// Error caused when combining epochs converted with different leap second handling.
+    #[error("Epochs converted with different leap second handling: {0:?} and {1:?}")]
+    MixedLeapHandling(LeapHandling, LeapHandling),
+    /// Error caused when rebasing an epoch whose leap second handling is unknown.
+    #[error("Unknown leap second handling for {0}")]
+    UnknownLeapHandling(Gpst),
+    /// Error caused when an instant is past the date through which the builtin leap second table is known complete.
+    #[error("Leap second table expired on {expires} for {instant}")]
+    LeapTableExpired {
+        /// The instant.
+        instant: DateTime<Utc>,
+        /// Expiry of the builtin leap second table.
+        expires: DateTime<Utc>,
+    },
+    /// Error caused when no leap second table file can be read from a tzdata directory.
+    #[error("No leap second table in the tzdata directory")]
+    LeapTableUnavailable,
+    /// Error caused when a conversion does not support a time scale.
+    #[error("Unsupported time scale: {0:?}")]
+    UnsupportedTimeScale(TimeScale),
+    /// Error caused when a UT1 provider has no DUT1 value for an instant.
+    #[error("No DUT1 value for {0}")]
+    Ut1Unavailable(DateTime<Utc>),
+    /// Error caused when a time input is NaN or infinite.
+    #[error("Invalid time input, must be finite: {0}")]
+    NonFiniteInput(f64),
+    /// Error caused when a resampled epoch falls in a gap between samples longer than allowed.
+    #[error("Gap between samples at {0} and {1} exceeds the maximum")]
+    Gap(Gpst, Gpst),
 }
 
 /// "GPS Epoch": 01-06-1980 00:00:00
 const GPS_EPOCH: i64 = 315964800 * TO_NANO_INT;
 const TO_NANO_INT: i64 = 1000000000;
 const TO_NANO_FLOAT: f64 = 1e9;
+const SECONDS_PER_DAY: f64 = 86400.0;
 const SECONDS_PER_WEEK: f64 = 604800.0;
 const NANOSECONDS_PER_WEEK: f64 = SECONDS_PER_WEEK * TO_NANO_FLOAT;
+/// Wide nanoseconds since GPS Epoch of the first and last nanosecond with an `i64` week number.
+const MIN_WIDE_NANOSECONDS: i128 = i64::MIN as i128 * NANOSECONDS_PER_WEEK as i128;
+const MAX_WIDE_NANOSECONDS: i128 = (i64::MAX as i128 + 1) * NANOSECONDS_PER_WEEK as i128 - 1;
 
 /// GPST data
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Gpst {
     /// Seconds since GPS Epoch
     pub seconds: f64,
@@ -63,85 +428,716 @@ pub struct Gpst {
     pub week: i64,
     /// Seconds in current week
     pub week_seconds: f64,
+    /// How leap seconds were handled converting from UTC, or `None` if unknown, e.g. for a raw week and week
+    /// seconds. Values converted with different handling are on different time scales, so they compare unequal and
+    /// [`Gpst::duration_since`] refuses to subtract them; see [`Gpst::rebase`].
+    pub leap_handling: Option<LeapHandling>,
+}
+
+impl Gpst {
+    /// Capture the current system time as GPS Time (GPST). Optionally, adjust for leap seconds.
+    #[cfg(feature = "std")]
+    pub fn now(leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        Utc::now().gpst(leap_seconds)
+    }
+
+    /// Parse an RFC 3339 date-time string (e.g. `2005-01-28T13:30:00Z`) and convert it to GPS Time (GPST).
+    /// Optionally, adjust for leap seconds.
+    pub fn parse_utc_str(
+        date_time: &str,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<Gpst, GpstError> {
+        DateTime::parse_from_rfc3339(date_time)
+            .map_err(|_| GpstError::Parse(ParseInput::new("RFC 3339 date-time")))?
+            .with_timezone(&Utc)
+            .gpst(leap_seconds)
+    }
+
+    /// Convert to an RFC 3339 UTC string with `precision` fractional second digits (at most 9), e.g.
+    /// `2005-01-28T13:30:00.000Z`. Optionally, adjust for leap seconds, failing with
+    /// [`GpstError::MixedLeapHandling`] if this value was converted with different handling.
+    pub fn to_utc_string(
+        &self,
+        precision: usize,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<String, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        self.check_converted_with(leap_seconds)?;
+        let precision = precision.min(9);
+        let unit = 10i64.pow(9 - precision as u32);
+        // Round to the requested precision first, so rounding carries into the calendar fields.
+        let unit_wide = unit as i128;
+        let nanoseconds =
+            (self.wide_nanoseconds_since_epoch() + unit_wide / 2).div_euclid(unit_wide) * unit_wide;
+        let date_time = from_wide_gps_nanoseconds(nanoseconds, leap_seconds)?;
+        let mut text = date_time.format("%Y-%m-%dT%H:%M:%S").to_string();
+        if precision > 0 {
+            let fraction = date_time.timestamp_subsec_nanos() as i64 / unit;
+            text += &format!(".{fraction:0precision$}");
+        }
+        text.push('Z');
+        Ok(text)
+    }
+
+    /// Build GPST data from a week and week seconds, failing on non-finite week seconds, week seconds outside of
+    /// `0..604800` and negative weeks.
+    pub fn new(week: i64, week_seconds: f64) -> Result<Gpst, GpstError> {
+        validate_week_seconds(week_seconds)?;
+        if week < 0 {
+            return Err(GpstError::BeforeGPSEpoch(TimeInput::Week(week)));
+        }
+        Ok(Gpst::normalized(week, week_seconds))
+    }
+
+    /// Build GPST data from nanoseconds since GPS Epoch.
+    pub(crate) fn from_gps_nanoseconds(nanoseconds: i64) -> Gpst {
+        Gpst::from_wide_nanoseconds(nanoseconds as i128)
+    }
+
+    /// [`Gpst::from_wide_nanoseconds`], or `None` if the week does not fit in `i64`.
+    pub(crate) fn checked_from_wide_nanoseconds(nanoseconds: i128) -> Option<Gpst> {
+        (MIN_WIDE_NANOSECONDS..=MAX_WIDE_NANOSECONDS)
+            .contains(&nanoseconds)
+            .then(|| Gpst::from_wide_nanoseconds(nanoseconds))
+    }
+
+    /// [`Gpst::from_gps_nanoseconds`] without the `i64` limit, for nanoseconds of any week number.
+    pub(crate) fn from_wide_nanoseconds(nanoseconds: i128) -> Gpst {
+        let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+        let week = nanoseconds.div_euclid(week_nanoseconds) as i64;
+        let week_seconds = nanoseconds.rem_euclid(week_nanoseconds) as f64 / TO_NANO_FLOAT;
+        // Same rounding as `normalized`, so equal epochs compare equal whichever way they were built.
+        Gpst {
+            seconds: week as f64 * SECONDS_PER_WEEK + week_seconds,
+            week,
+            week_seconds,
+            leap_handling: None,
+        }
+    }
+
+    /// Build GPST data as [`GpstLike::gpst`] does, with `seconds` truncated to whole seconds.
+    pub(crate) fn from_converted_nanoseconds(
+        nanoseconds: i128,
+        leap_seconds: LeapHandling,
+    ) -> Gpst {
+        let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+        Gpst {
+            seconds: (nanoseconds / TO_NANO_INT as i128) as f64,
+            week: nanoseconds.div_euclid(week_nanoseconds) as i64,
+            week_seconds: nanoseconds.rem_euclid(week_nanoseconds) as f64 / TO_NANO_FLOAT,
+            leap_handling: Some(leap_seconds),
+        }
+    }
+
+    /// The same GPST data, recorded as converted with the given leap second handling. Use this to label raw values
+    /// whose time scale is known, e.g. receiver week and time of week, which are true GPS time
+    /// ([`LeapHandling::Step`]).
+    pub fn with_leap_handling(mut self, leap_seconds: impl Into<LeapHandling>) -> Gpst {
+        self.leap_handling = Some(leap_seconds.into());
+        self
+    }
+
+    /// Carry the leap second handling of the epoch this one was derived from.
+    pub(crate) fn derived_from(mut self, source: &Gpst) -> Gpst {
+        self.leap_handling = source.leap_handling;
+        self
+    }
+
+    /// Convert to the time scale of another leap second handling, through the UTC instant this value represents.
+    /// Fails if this value's handling is unknown.
+    pub fn rebase(self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        let from = self
+            .leap_handling
+            .ok_or(GpstError::UnknownLeapHandling(self))?;
+        let leap_seconds = leap_seconds.into();
+        if from == leap_seconds {
+            return Ok(self);
+        }
+        let date_time =
+            from_gps_nanoseconds_with(self.wide_nanoseconds_since_epoch(), from, num_leaps)?;
+        let nanoseconds = gps_nanoseconds_with(&date_time, leap_seconds, num_leaps_utc)?;
+        Ok(Gpst::from_converted_nanoseconds(nanoseconds, leap_seconds))
+    }
+
+    /// Time elapsed since an earlier epoch, failing if the two were converted with different leap second handling.
+    /// Values of unknown handling are assumed to match.
+    pub fn duration_since(&self, earlier: &Gpst) -> Result<TimeDelta, GpstError> {
+        self.check_leap_handling(earlier)?;
+        let nanoseconds =
+            self.wide_nanoseconds_since_epoch() - earlier.wide_nanoseconds_since_epoch();
+        i64::try_from(nanoseconds)
+            .map(TimeDelta::nanoseconds)
+            .map_err(|_| GpstError::Overflow(TimeInput::GpsNanoseconds(nanoseconds)))
+    }
+
+    /// Order against another epoch, failing if the two were converted with different leap second handling. Values
+    /// of unknown handling are assumed to match.
+    pub fn try_cmp(&self, other: &Gpst) -> Result<Ordering, GpstError> {
+        self.check_leap_handling(other)?;
+        Ok(self
+            .wide_nanoseconds_since_epoch()
+            .cmp(&other.wide_nanoseconds_since_epoch()))
+    }
+
+    pub(crate) fn check_leap_handling(&self, other: &Gpst) -> Result<(), GpstError> {
+        match (self.leap_handling, other.leap_handling) {
+            (Some(left), Some(right)) if left != right => {
+                Err(GpstError::MixedLeapHandling(left, right))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that this value was converted with `leap_seconds`, before converting it back to UTC with them. Values
+    /// of unknown handling are assumed to match.
+    pub(crate) fn check_converted_with(&self, leap_seconds: LeapHandling) -> Result<(), GpstError> {
+        match self.leap_handling {
+            Some(handling) if handling != leap_seconds => {
+                Err(GpstError::MixedLeapHandling(handling, leap_seconds))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Weeks since GPS Epoch as a single float, e.g. `2280.42857` for Thursday noon of week 2280.
+    ///
+    /// An `f64` carries 53 significant bits, about 12 of which go to a current week number, so the week fraction
+    /// resolves to roughly 0.3 µs; prefer week and week seconds where that matters.
+    pub fn fractional_week(&self) -> f64 {
+        self.week as f64 + self.week_seconds / SECONDS_PER_WEEK
+    }
+
+    /// Build GPST data from a fractional week, the inverse of [`Gpst::fractional_week`] within its precision.
+    ///
+    /// Fails on non-finite and negative weeks.
+    pub fn from_fractional_week(weeks: f64) -> Result<Gpst, GpstError> {
+        if !weeks.is_finite() {
+            return Err(GpstError::NonFiniteInput(weeks));
+        }
+        if weeks < 0.0 {
+            return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(
+                weeks * SECONDS_PER_WEEK,
+            )));
+        }
+        let week = weeks.floor();
+        Ok(Gpst::normalized(
+            week as i64,
+            (weeks - week) * SECONDS_PER_WEEK,
+        ))
+    }
+
+    /// Whole seconds since GPS Epoch, from the week number and week seconds rounded to nanoseconds rather than the
+    /// lossy `seconds` field. Floors before GPS Epoch, so [`Gpst::subsec_nanos`] is never negative, and saturates
+    /// beyond `i64` for garbage week numbers.
+    pub fn total_seconds(&self) -> i64 {
+        clamp_to_i64(self.total_nanoseconds().div_euclid(TO_NANO_INT as i128))
+    }
+
+    /// Nanoseconds since GPS Epoch, from the week number and week seconds rounded to nanoseconds.
+    pub fn total_nanoseconds(&self) -> i128 {
+        self.wide_nanoseconds_since_epoch()
+    }
+
+    /// Nanoseconds past [`Gpst::total_seconds`], less than one second.
+    pub fn subsec_nanos(&self) -> u32 {
+        self.total_nanoseconds().rem_euclid(TO_NANO_INT as i128) as u32
+    }
+
+    /// Nanoseconds since GPS Epoch, from the week number and rounded week seconds, for any week number.
+    pub(crate) fn wide_nanoseconds_since_epoch(&self) -> i128 {
+        self.week as i128 * NANOSECONDS_PER_WEEK as i128 + self.week_nanoseconds() as i128
+    }
+
+    /// [`Gpst::wide_nanoseconds_since_epoch`], or `None` for garbage week seconds, e.g. NaN.
+    pub(crate) fn checked_nanoseconds_since_epoch(&self) -> Option<i128> {
+        Some(
+            self.week as i128 * NANOSECONDS_PER_WEEK as i128
+                + checked_nanoseconds(self.week_seconds)? as i128,
+        )
+    }
+
+    /// Week seconds rounded to whole nanoseconds, removing float noise such as `x.999999999`.
+    pub(crate) fn week_nanoseconds(&self) -> i64 {
+        (self.week_seconds * TO_NANO_FLOAT).round() as i64
+    }
+
+    /// Build GPST data from a week and week seconds, carrying week seconds outside of `0..604800` into the week.
+    pub(crate) fn normalized(week: i64, week_seconds: f64) -> Gpst {
+        let week = week + week_seconds.div_euclid(SECONDS_PER_WEEK) as i64;
+        let week_seconds = week_seconds.rem_euclid(SECONDS_PER_WEEK);
+        Gpst {
+            seconds: week as f64 * SECONDS_PER_WEEK + week_seconds,
+            week,
+            week_seconds,
+            leap_handling: None,
+        }
+    }
+}
+
+/// Formats as `<week> <week seconds>`, e.g. `1307 480613`.
+///
+/// Precision sets the fractional digits of the week seconds, and width pads the whole value (right-aligned by
+/// default), so `format!("{gpst:>20.3}")` yields `      1307 480613.000`.
+impl fmt::Display for Gpst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        let write_text = |writer: &mut dyn Write| match precision {
+            Some(precision) => write!(writer, "{} {:.*}", self.week, precision, self.week_seconds),
+            None => write!(writer, "{} {}", self.week, self.week_seconds),
+        };
+        // Measure the text before padding it, rather than formatting it into a String.
+        let mut length = CharCount(0);
+        write_text(&mut length)?;
+        let padding = f.width().unwrap_or(0).saturating_sub(length.0);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Left) => (0, padding),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Right) | None => (padding, 0),
+        };
+        for _ in 0..before {
+            f.write_char(f.fill())?;
+        }
+        write_text(f)?;
+        for _ in 0..after {
+            f.write_char(f.fill())?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters written to it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.0 += text.chars().count();
+        Ok(())
+    }
 }
 
 //Trait that extends [`chrono::DateTime`] / [`chrono::Utc`] for GPS Time (GPST).
 pub trait GpstLike {
     /// Convert to GPS Time (GPST) from DateTime<UTC>. Optionally, adjust for leap seconds.
-    fn gpst(&self, leap_seconds: bool) -> Result<Gpst, GpstError>;
+    fn gpst(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError>;
+
+    /// Convert to GPS Time (GPST), representing times before GPS Epoch as negative weeks (with week seconds still
+    /// in `0..604800`) instead of failing. Optionally, adjust for leap seconds.
+    fn gpst_signed(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError>;
+
+    /// Convert to GPS Time (GPST) as [`GpstLike::gpst`] does, rounding the week seconds, e.g. to whole seconds
+    /// with [`Round::Nearest`].
+    fn gpst_rounded(
+        &self,
+        leap_seconds: impl Into<LeapHandling>,
+        round: Round,
+    ) -> Result<Gpst, GpstError> {
+        self.gpst(leap_seconds).map(|gpst| gpst.rounded(round))
+    }
 }
 
 impl GpstLike for DateTime<Utc> {
-    fn gpst(&self, leap_seconds: bool) -> Result<Gpst, GpstError> {
-        let timestamp_nanos = self
-            .timestamp_nanos_opt()
-            .ok_or(GpstError::TimestampNano(self.to_rfc3339()))?;
-        let mut nanoseconds = timestamp_nanos - GPS_EPOCH;
-        if leap_seconds {
-            nanoseconds += num_leaps(nanoseconds);
-        }
-        if nanoseconds < 0 {
-            GpstError::BeforeGPSEpoch(self.to_rfc3339());
-        }
-        let week = nanoseconds as f64 / NANOSECONDS_PER_WEEK;
-        let week_start = from_gpst(week as i64, 0.0, leap_seconds)?;
-        let week_start_timestamp_nanos =
-            week_start
-                .timestamp_nanos_opt()
-                .ok_or(GpstError::TimestampNano(format!(
-                    "Week Start: {}",
-                    week_start.to_rfc3339()
-                )))?;
-        Ok(Gpst {
-            seconds: (nanoseconds / TO_NANO_INT) as f64,
-            week: week as i64,
-            week_seconds: (timestamp_nanos - week_start_timestamp_nanos) as f64 / TO_NANO_FLOAT,
-        })
+    fn gpst(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        let nanoseconds = gps_nanoseconds_with(self, leap_seconds, num_leaps_utc)?;
+        Ok(Gpst::from_converted_nanoseconds(nanoseconds, leap_seconds))
+    }
+
+    fn gpst_signed(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        let leap_seconds = leap_seconds.into();
+        let nanoseconds = signed_gps_nanoseconds_with(self, leap_seconds, num_leaps_utc);
+        Ok(Gpst::from_converted_nanoseconds(nanoseconds, leap_seconds))
     }
 }
 
+/// Converts as [`GpstLike::gpst`] with the default [`LeapHandling::Step`]. Use [`Converter`] to inject another policy.
+impl TryFrom<DateTime<Utc>> for Gpst {
+    type Error = GpstError;
+
+    fn try_from(date_time: DateTime<Utc>) -> Result<Gpst, GpstError> {
+        date_time.gpst(LeapHandling::default())
+    }
+}
+
+/// Converts as [`from_gpst`] with the leap second handling recorded in the value, or the default
+/// [`LeapHandling::Step`] if unknown. Use [`Converter`] to inject another policy.
+impl TryFrom<Gpst> for DateTime<Utc> {
+    type Error = GpstError;
+
+    fn try_from(gpst: Gpst) -> Result<DateTime<Utc>, GpstError> {
+        from_gpst(
+            gpst.week,
+            gpst.week_seconds,
+            gpst.leap_handling.unwrap_or_default(),
+        )
+    }
+}
+
+/// Wide nanoseconds since GPS Epoch for a DateTime<Utc>, covering chrono's whole date range. Optionally, adjust for
+/// leap seconds.
+pub(crate) fn wide_gps_nanoseconds(
+    date_time: &DateTime<Utc>,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<i128, GpstError> {
+    gps_nanoseconds_with(date_time, leap_seconds.into(), num_leaps_utc)
+}
+
+/// Wide nanoseconds since GPS Epoch for a DateTime<Utc>, covering chrono's whole date range, with a custom lookup of
+/// stepped leap nanoseconds for UTC nanoseconds since GPS Epoch.
+pub(crate) fn gps_nanoseconds_with(
+    date_time: &DateTime<Utc>,
+    leap_seconds: LeapHandling,
+    num_leaps_utc: impl FnMut(i64) -> i64,
+) -> Result<i128, GpstError> {
+    let nanoseconds = signed_gps_nanoseconds_with(date_time, leap_seconds, num_leaps_utc);
+    if nanoseconds < 0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::Utc(*date_time)));
+    }
+    Ok(nanoseconds)
+}
+
+/// [`gps_nanoseconds_with`], negative before GPS Epoch.
+pub(crate) fn signed_gps_nanoseconds_with(
+    date_time: &DateTime<Utc>,
+    leap_seconds: LeapHandling,
+    mut num_leaps_utc: impl FnMut(i64) -> i64,
+) -> i128 {
+    let timestamp_nanos = date_time.timestamp() as i128 * TO_NANO_INT as i128
+        + date_time.timestamp_subsec_nanos() as i128;
+    let nanoseconds = timestamp_nanos - GPS_EPOCH as i128;
+    // The leap second tables only span i64 nanoseconds, so clamping the lookup does not change the count.
+    let lookup = clamp_to_i64(nanoseconds);
+    let leap_nanoseconds = match leap_seconds {
+        LeapHandling::None => 0,
+        // chrono folds a 23:59:60 reading onto the following second, so move it back into the inserted second.
+        LeapHandling::Step if is_inserted_utc(date_time, lookup) => {
+            num_leaps_utc(lookup) - TO_NANO_INT
+        }
+        LeapHandling::Step => num_leaps_utc(lookup),
+        LeapHandling::Smear24h => smeared_leaps_utc(lookup),
+    };
+    nanoseconds + leap_nanoseconds as i128
+}
+
+pub(crate) fn clamp_to_i64(nanoseconds: i128) -> i64 {
+    nanoseconds.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
 /// Given seconds since GPS Epoch, convert to a DateTime<Utc>. Optionally, adjust for leap seconds.
-pub fn from_gpst_seconds(seconds: f64, leap_seconds: bool) -> Result<DateTime<Utc>, GpstError> {
-    let mut nanoseconds = (seconds * TO_NANO_FLOAT) as i64;
-    if leap_seconds {
-        nanoseconds -= num_leaps(nanoseconds);
+///
+/// Fails on NaN or infinite seconds, and on seconds before GPS Epoch.
+pub fn from_gpst_seconds(
+    seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    if !seconds.is_finite() {
+        return Err(GpstError::NonFiniteInput(seconds));
+    }
+    if seconds < 0.0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(seconds)));
     }
-    let date_time = DateTime::from_timestamp_nanos(nanoseconds + GPS_EPOCH);
-    Ok(date_time)
+    from_gpst_seconds_unchecked(seconds, leap_seconds)
+}
+
+/// [`from_gpst_seconds`] without input validation: NaN converts as 0 and infinite seconds saturate.
+pub fn from_gpst_seconds_unchecked(
+    seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    from_wide_gps_nanoseconds((seconds * TO_NANO_FLOAT) as i128, leap_seconds)
+}
+
+/// [`from_gpst_seconds`], also failing with [`GpstError::Overflow`] instead of saturating when the seconds do not fit
+/// in `i128` nanoseconds. Validation and truncation to nanoseconds are otherwise the same.
+pub fn checked_from_gpst_seconds(
+    seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    // i128::MAX is not representable as f64, so the bound is exclusive. Non-finite seconds fail in from_gpst_seconds.
+    if seconds.is_finite() && seconds * TO_NANO_FLOAT >= i128::MAX as f64 {
+        return Err(GpstError::Overflow(TimeInput::GpsSeconds(seconds)));
+    }
+    from_gpst_seconds(seconds, leap_seconds)
+}
+
+/// [`from_gpst`], for symmetry with [`checked_from_gpst_seconds`]. Validated week seconds always fit in `i128`
+/// nanoseconds, so there is nothing further to check.
+pub fn checked_from_gpst(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    from_gpst(week, week_seconds, leap_seconds)
+}
+
+/// Seconds rounded to whole nanoseconds, or `None` if not finite or outside of `i64` nanoseconds.
+pub(crate) fn checked_nanoseconds(seconds: f64) -> Option<i64> {
+    let nanoseconds = (seconds * TO_NANO_FLOAT).round();
+    // i64::MAX is not representable as f64, so the upper bound is exclusive.
+    (nanoseconds >= i64::MIN as f64 && nanoseconds < i64::MAX as f64).then_some(nanoseconds as i64)
+}
+
+/// Given whole seconds since GPS Epoch and a nanosecond remainder, convert to a DateTime<Utc> without going through
+/// a lossy f64. Optionally, adjust for leap seconds.
+pub fn from_gpst_seconds_parts(
+    seconds: i64,
+    nanoseconds: u32,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    if nanoseconds as i64 >= TO_NANO_INT {
+        return Err(GpstError::Nanoseconds(nanoseconds));
+    }
+    let total_nanoseconds = seconds as i128 * TO_NANO_INT as i128 + nanoseconds as i128;
+    from_wide_gps_nanoseconds(total_nanoseconds, leap_seconds)
+}
+
+/// Given nanoseconds since GPS Epoch, convert to a DateTime<Utc>. Optionally, adjust for leap seconds.
+pub(crate) fn from_gps_nanoseconds(
+    nanoseconds: i64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    from_wide_gps_nanoseconds(nanoseconds as i128, leap_seconds)
+}
+
+/// Given wide nanoseconds since GPS Epoch, convert to a DateTime<Utc> anywhere in chrono's date range. Optionally,
+/// adjust for leap seconds.
+pub(crate) fn from_wide_gps_nanoseconds(
+    nanoseconds: i128,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    from_gps_nanoseconds_with(nanoseconds, leap_seconds.into(), num_leaps)
+}
+
+/// [`from_wide_gps_nanoseconds`] with a custom lookup of stepped leap nanoseconds for GPS nanoseconds since GPS
+/// Epoch.
+pub(crate) fn from_gps_nanoseconds_with(
+    nanoseconds: i128,
+    leap_seconds: LeapHandling,
+    num_leaps: impl FnMut(i64) -> i64,
+) -> Result<DateTime<Utc>, GpstError> {
+    let timestamp_nanos = unix_nanoseconds_with(nanoseconds, leap_seconds, num_leaps);
+    let out_of_range = || GpstError::OutOfRange(TimeInput::GpsNanoseconds(nanoseconds));
+    let seconds = i64::try_from(timestamp_nanos.div_euclid(TO_NANO_INT as i128))
+        .map_err(|_| out_of_range())?;
+    let subsec_nanos = timestamp_nanos.rem_euclid(TO_NANO_INT as i128) as u32;
+    DateTime::from_timestamp(seconds, subsec_nanos).ok_or_else(out_of_range)
+}
+
+/// Unix nanoseconds of wide nanoseconds since GPS Epoch, with a custom lookup of stepped leap nanoseconds.
+pub(crate) fn unix_nanoseconds_with(
+    nanoseconds: i128,
+    leap_seconds: LeapHandling,
+    mut num_leaps: impl FnMut(i64) -> i64,
+) -> i128 {
+    let lookup = clamp_to_i64(nanoseconds);
+    let leap_nanoseconds = match leap_seconds {
+        LeapHandling::None => 0,
+        LeapHandling::Step => num_leaps(lookup),
+        LeapHandling::Smear24h => smeared_leaps(lookup),
+    };
+    nanoseconds - leap_nanoseconds as i128 + GPS_EPOCH as i128
 }
 
 /// Given weeks since GPS Epoch and week seconds, convert to a DateTime<Utc>. Optionally, adjust for leap seconds.
+///
+/// Fails on NaN or infinite week seconds, week seconds outside of `0..604800`, and negative weeks, so corrupt input
+/// cannot produce a silently wrong date. See [`from_gpst_unchecked`] to carry week seconds into the week instead.
 pub fn from_gpst(
     week: i64,
     week_seconds: f64,
-    leap_seconds: bool,
+    leap_seconds: impl Into<LeapHandling>,
 ) -> Result<DateTime<Utc>, GpstError> {
-    let gps_seconds = (week as f64 * SECONDS_PER_WEEK) + week_seconds;
-    from_gpst_seconds(gps_seconds, leap_seconds)
+    validate_week(week, week_seconds)?;
+    from_gpst_unchecked(week, week_seconds, leap_seconds)
 }
 
-/// Leap seconds since GPS Epoch.
-const LEAP_SECONDS: [i64; 18] = [
-    46828800, 78364801, 109900802, 173059203, 252028804, 315187205, 346723206, 393984007,
-    425520008, 457056009, 504489610, 551750411, 599184012, 820108813, 914803214, 1025136015,
-    1119744016, 1167264017,
+/// [`from_gpst`] without input validation: week seconds outside of the week carry into it, NaN converts as 0 and
+/// infinite week seconds saturate.
+pub fn from_gpst_unchecked(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    // The week start is exact in integer nanoseconds, so far-future weeks keep nanosecond week seconds.
+    let nanoseconds =
+        week as i128 * NANOSECONDS_PER_WEEK as i128 + (week_seconds * TO_NANO_FLOAT) as i128;
+    from_wide_gps_nanoseconds(nanoseconds, leap_seconds)
+}
+
+/// Given weeks since GPS Epoch and week seconds, convert to a DateTime<Utc>, accepting negative weeks for times
+/// before GPS Epoch as produced by [`GpstLike::gpst_signed`]. Optionally, adjust for leap seconds.
+pub fn from_gpst_signed(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<DateTime<Utc>, GpstError> {
+    validate_week_seconds(week_seconds)?;
+    from_gpst_unchecked(week, week_seconds, leap_seconds)
+}
+
+/// Check a week and week seconds as [`from_gpst`] does: the week must not be negative and the week seconds must be
+/// finite and within one week.
+pub(crate) fn validate_week(week: i64, week_seconds: f64) -> Result<(), GpstError> {
+    if week < 0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::Week(week)));
+    }
+    validate_week_seconds(week_seconds)
+}
+
+/// Check that week seconds are finite and within one week.
+fn validate_week_seconds(week_seconds: f64) -> Result<(), GpstError> {
+    if !week_seconds.is_finite() {
+        return Err(GpstError::NonFiniteInput(week_seconds));
+    }
+    if !(0.0..SECONDS_PER_WEEK).contains(&week_seconds) {
+        return Err(GpstError::TowOutOfRange {
+            tow: week_seconds,
+            valid: 0.0..SECONDS_PER_WEEK,
+        });
+    }
+    Ok(())
+}
+
+/// A leap second inserted since GPS Epoch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LeapSecond {
+    /// UTC date from whose start (`00:00:00`) the new offset is in effect, i.e. the day after the inserted `23:59:60`.
+    pub effective: NaiveDate,
+    /// Seconds since GPS Epoch at the start of the inserted second.
+    pub gps_seconds: i64,
+    /// GPS−UTC offset in whole seconds from the effective date on.
+    pub gps_utc_offset: i64,
+}
+
+impl LeapSecond {
+    const fn new(effective: NaiveDate, gps_seconds: i64, gps_utc_offset: i64) -> LeapSecond {
+        LeapSecond {
+            effective,
+            gps_seconds,
+            gps_utc_offset,
+        }
+    }
+}
+
+const fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    match NaiveDate::from_ymd_opt(year, month, day) {
+        Some(date) => date,
+        None => panic!("leap second dates are valid"),
+    }
+}
+
+/// Date through which [`LEAP_SECOND_TABLE`] is known complete, the expiry of the IERS `leap-seconds.list` it was last
+/// checked against. Leap seconds announced after it are missing until the table is updated.
+pub const LEAP_TABLE_EXPIRES: NaiveDate = date(2026, 6, 28);
+
+/// Leap seconds inserted since GPS Epoch, in chronological order.
+pub const LEAP_SECOND_TABLE: &[LeapSecond] = &[
+    LeapSecond::new(date(1981, 7, 1), 46828800, 1),
+    LeapSecond::new(date(1982, 7, 1), 78364801, 2),
+    LeapSecond::new(date(1983, 7, 1), 109900802, 3),
+    LeapSecond::new(date(1985, 7, 1), 173059203, 4),
+    LeapSecond::new(date(1988, 1, 1), 252028804, 5),
+    LeapSecond::new(date(1990, 1, 1), 315187205, 6),
+    LeapSecond::new(date(1991, 1, 1), 346723206, 7),
+    LeapSecond::new(date(1992, 7, 1), 393984007, 8),
+    LeapSecond::new(date(1993, 7, 1), 425520008, 9),
+    LeapSecond::new(date(1994, 7, 1), 457056009, 10),
+    LeapSecond::new(date(1996, 1, 1), 504489610, 11),
+    LeapSecond::new(date(1997, 7, 1), 551750411, 12),
+    LeapSecond::new(date(1999, 1, 1), 599184012, 13),
+    LeapSecond::new(date(2006, 1, 1), 820108813, 14),
+    LeapSecond::new(date(2009, 1, 1), 914803214, 15),
+    LeapSecond::new(date(2012, 7, 1), 1025136015, 16),
+    LeapSecond::new(date(2015, 7, 1), 1119744016, 17),
+    LeapSecond::new(date(2017, 1, 1), 1167264017, 18),
 ];
 
-/// Count how many leap nanoseconds have occured since a given GPS timestamp.
-fn num_leaps(gps_nanoseconds: i64) -> i64 {
-    let mut count = 0;
-    for leap_second in LEAP_SECONDS {
-        let leap_nanoseconds = leap_second * TO_NANO_INT;
-        if leap_nanoseconds < gps_nanoseconds {
-            count += TO_NANO_INT;
+/// Leap seconds since GPS Epoch.
+const LEAP_SECONDS: [i64; LEAP_SECOND_TABLE.len()] = {
+    let mut leap_seconds = [0; LEAP_SECOND_TABLE.len()];
+    let mut index = 0;
+    while index < leap_seconds.len() {
+        leap_seconds[index] = LEAP_SECOND_TABLE[index].gps_seconds;
+        index += 1;
+    }
+    leap_seconds
+};
+
+/// Fail the build if the builtin table drifts from the vendored `data/leap-seconds.list`.
+#[cfg(feature = "verify-leap-table")]
+const _: () = {
+    const LEAP_SECONDS_LIST: &[i64] = &include!(concat!(env!("OUT_DIR"), "/leap_seconds_list.rs"));
+    assert!(
+        LEAP_SECONDS_LIST.len() == LEAP_SECONDS.len(),
+        "LEAP_SECONDS does not match data/leap-seconds.list"
+    );
+    let mut index = 0;
+    while index < LEAP_SECONDS.len() {
+        assert!(
+            LEAP_SECONDS_LIST[index] == LEAP_SECONDS[index],
+            "LEAP_SECONDS does not match data/leap-seconds.list"
+        );
+        index += 1;
+    }
+};
+
+/// Leap seconds since GPS Epoch in nanoseconds, on the GPS time scale.
+pub(crate) const LEAP_NANOSECONDS: [i64; LEAP_SECONDS.len()] = {
+    let mut leap_nanoseconds = [0; LEAP_SECONDS.len()];
+    let mut index = 0;
+    while index < leap_nanoseconds.len() {
+        leap_nanoseconds[index] = LEAP_SECONDS[index] * TO_NANO_INT;
+        index += 1;
+    }
+    leap_nanoseconds
+};
+
+/// Leap seconds since GPS Epoch in nanoseconds, on the UTC time scale: each entry is shifted back by the leap seconds
+/// preceding it.
+pub(crate) const LEAP_NANOSECONDS_UTC: [i64; LEAP_SECONDS.len()] = {
+    let mut leap_nanoseconds = [0; LEAP_SECONDS.len()];
+    let mut index = 0;
+    while index < leap_nanoseconds.len() {
+        leap_nanoseconds[index] = (LEAP_SECONDS[index] - index as i64) * TO_NANO_INT;
+        index += 1;
+    }
+    leap_nanoseconds
+};
+
+/// Count the entries of a sorted leap table before a value, or also at it when `inclusive`, by binary search.
+pub(crate) const fn count_leaps(table: &[i64], value: i64, inclusive: bool) -> usize {
+    let (mut low, mut high) = (0, table.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if table[middle] < value || (inclusive && table[middle] == value) {
+            low = middle + 1;
+        } else {
+            high = middle;
         }
     }
-    count
+    low
+}
+
+/// Count how many leap nanoseconds have occured since a given GPS timestamp.
+const fn num_leaps(gps_nanoseconds: i64) -> i64 {
+    count_leaps(&LEAP_NANOSECONDS, gps_nanoseconds, false) as i64 * TO_NANO_INT
+}
+
+/// Count how many leap nanoseconds have occured since a given UTC timestamp, relative to GPS Epoch.
+pub(crate) const fn num_leaps_utc(utc_nanoseconds: i64) -> i64 {
+    count_leaps(&LEAP_NANOSECONDS_UTC, utc_nanoseconds, true) as i64 * TO_NANO_INT
 }
 
+#[cfg(test)]
 mod tests {
-    use crate::{from_gpst, Gpst, GpstLike, GPS_EPOCH, LEAP_SECONDS};
-    use chrono::{DateTime, NaiveDate};
+    use crate::{
+        checked_from_gpst, checked_from_gpst_seconds, from_gpst, from_gpst_seconds,
+        from_gpst_seconds_parts, from_gpst_seconds_unchecked, from_gpst_signed,
+        from_gpst_unchecked, num_leaps, num_leaps_utc, wide_gps_nanoseconds, Gpst, GpstError,
+        GpstLike, LeapHandling, ParseInput, TimeInput, GPS_EPOCH, LEAP_SECONDS, LEAP_SECOND_TABLE,
+        LEAP_TABLE_EXPIRES, NANOSECONDS_PER_WEEK, TO_NANO_INT,
+    };
+    use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
+    use core::cmp::Ordering;
 
     #[test]
     fn to() {
@@ -155,7 +1151,8 @@ mod tests {
             Gpst {
                 seconds: 790954213.0,
                 week: 1307,
-                week_seconds: 480613.0
+                week_seconds: 480613.0,
+                leap_handling: Some(crate::LeapHandling::Step),
             }
         );
     }
@@ -169,10 +1166,431 @@ mod tests {
         assert_eq!(from_gpst(1307, 480613.0, true).unwrap(), date_time)
     }
 
+    #[test]
+    fn integer_accessors() {
+        let gpst = Gpst::new(1307, 480613.000000001).unwrap();
+        assert_eq!(gpst.total_seconds(), 790954213);
+        assert_eq!(gpst.total_nanoseconds(), 790954213000000001);
+        assert_eq!(gpst.subsec_nanos(), 1);
+        // Float noise below a nanosecond rounds away.
+        let noisy = Gpst::new(1307, 480612.9999999999).unwrap();
+        assert_eq!(
+            (noisy.total_seconds(), noisy.subsec_nanos()),
+            (790954213, 0)
+        );
+        let before = Gpst::normalized(-1, 604799.5);
+        assert_eq!(
+            (before.total_seconds(), before.subsec_nanos()),
+            (-1, 500000000)
+        );
+        assert_eq!(Gpst::normalized(i64::MAX, 0.0).total_seconds(), i64::MAX);
+    }
+
+    #[test]
+    fn fractional_week() {
+        let gpst = Gpst::new(2280, 259200.0).unwrap();
+        assert!((gpst.fractional_week() - 2280.4285714285716).abs() < 1e-12);
+        let decoded = Gpst::from_fractional_week(gpst.fractional_week()).unwrap();
+        assert_eq!(decoded.week, 2280);
+        assert!((decoded.week_seconds - 259200.0).abs() < 1e-6);
+        assert_eq!(
+            Gpst::from_fractional_week(1307.0).unwrap(),
+            Gpst::normalized(1307, 0.0)
+        );
+        assert!(matches!(
+            Gpst::from_fractional_week(-0.5),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+        assert!(Gpst::from_fractional_week(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn checked_from() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        assert_eq!(checked_from_gpst(1307, 480613.0, true).unwrap(), date_time);
+        assert_eq!(
+            checked_from_gpst_seconds(790954213.0, true).unwrap(),
+            date_time
+        );
+        assert!(checked_from_gpst(i64::MAX, 0.0, true).is_err());
+        assert!(checked_from_gpst(1307, f64::INFINITY, true).is_err());
+        assert!(checked_from_gpst_seconds(f64::NAN, true).is_err());
+        assert!(checked_from_gpst_seconds(1e16, true).is_err());
+
+        // At least as strict as the unchecked default, with the same errors and truncation.
+        for (week, week_seconds) in [
+            (-1, 0.0),
+            (1307, -1.0),
+            (1307, 604800.0),
+            (1307, f64::NAN),
+            (0, 0.000_000_000_6),
+        ] {
+            assert_eq!(
+                format!("{:?}", checked_from_gpst(week, week_seconds, true)),
+                format!("{:?}", from_gpst(week, week_seconds, true))
+            );
+        }
+        for seconds in [-1.0, f64::NAN, f64::INFINITY, 0.000_000_000_6] {
+            assert_eq!(
+                format!("{:?}", checked_from_gpst_seconds(seconds, true)),
+                format!("{:?}", from_gpst_seconds(seconds, true))
+            );
+        }
+        assert_eq!(
+            checked_from_gpst_seconds(0.000_000_000_6, true).unwrap(),
+            from_gpst(0, 0.0, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        fn round_trip<T, U>(value: T) -> Result<T, GpstError>
+        where
+            U: TryFrom<T, Error = GpstError>,
+            T: TryFrom<U, Error = GpstError>,
+        {
+            T::try_from(U::try_from(value)?)
+        }
+
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let gpst = Gpst::try_from(date_time).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (1307, 480613.0));
+        assert_eq!(DateTime::<Utc>::try_from(gpst).unwrap(), date_time);
+        assert_eq!(round_trip::<_, Gpst>(date_time).unwrap(), date_time);
+        assert!(Gpst::try_from(DateTime::<Utc>::UNIX_EPOCH).is_err());
+        assert!(DateTime::<Utc>::try_from(Gpst::normalized(-1, 0.0)).is_err());
+    }
+
+    #[test]
+    fn leap_handling_tag() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let step = date_time.gpst(LeapHandling::Step).unwrap();
+        let none = date_time.gpst(LeapHandling::None).unwrap();
+        assert_eq!(step.leap_handling, Some(LeapHandling::Step));
+        assert_ne!(step, none);
+        assert_eq!(step.rebase(LeapHandling::None).unwrap(), none);
+        assert_eq!(none.rebase(LeapHandling::Step).unwrap(), step);
+        assert_eq!(DateTime::<Utc>::try_from(none).unwrap(), date_time);
+
+        assert_eq!(
+            step.duration_since(&none),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::Step,
+                LeapHandling::None
+            ))
+        );
+        assert!(none.try_cmp(&step).is_err());
+        let later = step.with_seconds_delta(1.5);
+        assert_eq!(later.leap_handling, Some(LeapHandling::Step));
+        assert_eq!(
+            later.duration_since(&step),
+            Ok(TimeDelta::milliseconds(1500))
+        );
+        assert_eq!(step.try_cmp(&later), Ok(Ordering::Less));
+
+        let raw = Gpst::normalized(1307, 480613.0);
+        assert_eq!(raw.try_cmp(&step), Ok(Ordering::Equal));
+        assert_eq!(
+            raw.rebase(LeapHandling::None),
+            Err(GpstError::UnknownLeapHandling(raw))
+        );
+        assert_eq!(raw.with_leap_handling(true), step);
+    }
+
+    #[test]
+    fn structured_errors() {
+        let date_time = Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            date_time.gpst(true),
+            Err(GpstError::BeforeGPSEpoch(TimeInput::Utc(date_time)))
+        );
+        assert_eq!(
+            from_gpst(1307, 604800.5, true),
+            Err(GpstError::TowOutOfRange {
+                tow: 604800.5,
+                valid: 0.0..604800.0
+            })
+        );
+        assert_eq!(
+            checked_from_gpst_seconds(1e30, true),
+            Err(GpstError::Overflow(TimeInput::GpsSeconds(1e30)))
+        );
+        assert_eq!(
+            from_gpst(1307, 604800.5, true).unwrap_err().to_string(),
+            "Invalid time of week, must be within 0.0..604800.0: 604800.5"
+        );
+        assert_eq!(
+            date_time.gpst(true).unwrap_err().to_string(),
+            "Invalid date-time for GPST, is earlier than GPS Epoch: 1970-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            Gpst::parse_utc_str("2005-01-28", true),
+            Err(GpstError::Parse(ParseInput::new("RFC 3339 date-time")))
+        );
+        assert_eq!(
+            GpstError::Parse(ParseInput::at_line("leapseconds", 3)).to_string(),
+            "Could not parse leapseconds on line 3"
+        );
+    }
+
+    #[test]
+    fn time_input_display() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_000_000_001)
+            .unwrap()
+            .and_utc();
+        for date_time in [
+            date_time,
+            date_time + TimeDelta::milliseconds(500),
+            date_time + TimeDelta::microseconds(1),
+            date_time + TimeDelta::nanoseconds(1),
+            leap_second,
+            DateTime::<Utc>::MIN_UTC,
+            DateTime::<Utc>::MAX_UTC,
+        ] {
+            assert_eq!(
+                TimeInput::Utc(date_time).to_string(),
+                date_time.to_rfc3339()
+            );
+        }
+    }
+
+    #[test]
+    fn validation() {
+        assert!(matches!(
+            from_gpst(1307, 604800.0, true),
+            Err(GpstError::TowOutOfRange { .. })
+        ));
+        assert!(matches!(
+            from_gpst(1307, f64::NAN, true),
+            Err(GpstError::NonFiniteInput(_))
+        ));
+        assert!(matches!(
+            from_gpst(-1, 0.0, true),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+        assert!(matches!(
+            from_gpst_seconds(f64::INFINITY, true),
+            Err(GpstError::NonFiniteInput(_))
+        ));
+        assert!(from_gpst_seconds(-1.0, true).is_err());
+        assert_eq!(
+            from_gpst_unchecked(1306, 604800.0 + 480613.0, true).unwrap(),
+            from_gpst(1307, 480613.0, true).unwrap()
+        );
+        assert!(from_gpst_seconds_unchecked(-1.0, false).is_ok());
+
+        assert_eq!(
+            Gpst::new(1307, 480613.0).unwrap(),
+            Gpst::normalized(1307, 480613.0)
+        );
+        assert!(Gpst::new(1307, -0.5).is_err());
+        assert!(Gpst::new(-1, 0.0).is_err());
+    }
+
+    #[test]
+    fn signed() {
+        let date_time = Utc.with_ymd_and_hms(1979, 12, 30, 12, 0, 0).unwrap();
+        assert!(date_time.gpst(true).is_err());
+        let gpst = date_time.gpst_signed(true).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (-1, 43200.0));
+        assert_eq!(gpst.seconds, -561600.0);
+        assert_eq!(
+            from_gpst_signed(gpst.week, gpst.week_seconds, true).unwrap(),
+            date_time
+        );
+
+        let date_time = Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap();
+        let gpst = date_time.gpst_signed(false).unwrap();
+        assert_eq!(gpst.seconds, -315964800.0);
+        assert_eq!(
+            from_gpst_signed(gpst.week, gpst.week_seconds, false).unwrap(),
+            date_time
+        );
+        // After GPS Epoch, both modes agree.
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        assert_eq!(
+            date_time.gpst_signed(true).unwrap(),
+            date_time.gpst(true).unwrap()
+        );
+    }
+
+    #[test]
+    fn week_split_is_exact() {
+        // Week boundaries on the GPS scale, right after a leap second: each split must match integer division of
+        // the GPS nanoseconds, with no float round-trip through the week start.
+        let utc = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        let start = Utc.with_ymd_and_hms(2017, 1, 7, 23, 59, 42).unwrap();
+        for offset in [-1, 0, 1, 999_999_999] {
+            for date_time in [utc, start] {
+                let date_time = date_time + TimeDelta::nanoseconds(offset);
+                let nanoseconds = wide_gps_nanoseconds(&date_time, true).unwrap();
+                let gpst = date_time.gpst(true).unwrap();
+                assert_eq!(
+                    gpst.week as i128,
+                    nanoseconds.div_euclid(NANOSECONDS_PER_WEEK as i128)
+                );
+                assert_eq!(
+                    gpst.week_nanoseconds() as i128,
+                    nanoseconds.rem_euclid(NANOSECONDS_PER_WEEK as i128)
+                );
+            }
+        }
+        assert_eq!(start.gpst(true).unwrap().week_seconds, 0.0);
+    }
+
+    #[test]
+    fn far_future() {
+        // Beyond 2262 (i64 Unix nanoseconds) and 2272 (i64 GPS nanoseconds).
+        for year in [2300, 9999, 100000] {
+            let date_time = Utc.with_ymd_and_hms(year, 1, 1, 12, 0, 0).unwrap();
+            let gpst = date_time.gpst(true).unwrap();
+            assert_eq!(gpst.week_seconds % 1.0, 0.0);
+            assert_eq!(
+                from_gpst_seconds_parts(gpst.seconds as i64, 0, true).unwrap(),
+                date_time
+            );
+            assert_eq!(
+                gpst.to_utc_string(0, true).unwrap(),
+                date_time.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+            );
+        }
+        let gpst = Utc
+            .with_ymd_and_hms(2300, 1, 1, 0, 0, 0)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        assert_eq!(gpst.week, 16696);
+        assert_eq!(
+            from_gpst(gpst.week, gpst.week_seconds, true).unwrap(),
+            Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert!(matches!(
+            from_gpst(i64::MAX, 0.0, true),
+            Err(crate::GpstError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn from_seconds_parts() {
+        let date_time = NaiveDate::from_ymd_opt(2005, 1, 28)
+            .unwrap()
+            .and_hms_nano_opt(13, 30, 0, 123_456_789)
+            .unwrap()
+            .and_utc();
+        assert_eq!(
+            from_gpst_seconds_parts(790954213, 123_456_789, true).unwrap(),
+            date_time
+        );
+        assert!(from_gpst_seconds_parts(790954213, 1_000_000_000, true).is_err());
+        assert!(from_gpst_seconds_parts(i64::MAX / 1000, 0, true).is_err());
+    }
+
+    #[test]
+    fn utc_strings() {
+        let gpst = Gpst::parse_utc_str("2005-01-28T13:30:00Z", true).unwrap();
+        assert_eq!(
+            gpst,
+            Gpst::normalized(1307, 480613.0).with_leap_handling(true)
+        );
+        assert_eq!(gpst.to_utc_string(0, true).unwrap(), "2005-01-28T13:30:00Z");
+        let gpst = Gpst::parse_utc_str("2005-01-28T14:30:00.1239+01:00", true).unwrap();
+        assert_eq!(
+            gpst.to_utc_string(3, true).unwrap(),
+            "2005-01-28T13:30:00.124Z"
+        );
+        assert_eq!(
+            gpst.to_utc_string(0, false),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::Step,
+                LeapHandling::None
+            ))
+        );
+        let raw = Gpst::normalized(gpst.week, gpst.week_seconds);
+        assert_eq!(raw.to_utc_string(0, false).unwrap(), "2005-01-28T13:30:13Z");
+        assert!(Gpst::parse_utc_str("2005-01-28 13:30", true).is_err());
+    }
+
+    #[test]
+    fn display() {
+        let gpst = Gpst::normalized(1307, 480613.25);
+        assert_eq!(gpst.to_string(), "1307 480613.25");
+        assert_eq!(format!("{gpst:.3}"), "1307 480613.250");
+        assert_eq!(format!("{gpst:>20.1}"), "       1307 480613.2");
+        assert_eq!(format!("{gpst:*<16.0}|"), "1307 480613*****|");
+        assert_eq!(format!("{gpst:^18}"), "  1307 480613.25  ");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn now() {
+        let gpst = Gpst::now(true).unwrap();
+        assert!(gpst.week >= 2400);
+        assert!(gpst.week_seconds < 604800.0);
+    }
+
+    #[test]
+    fn leap_second_table() {
+        let mut previous_offset = 0;
+        for leap_second in LEAP_SECOND_TABLE {
+            assert_eq!(leap_second.gps_utc_offset, previous_offset + 1);
+            let effective = leap_second
+                .effective
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc();
+            assert_eq!(
+                effective.timestamp() - GPS_EPOCH / TO_NANO_INT,
+                leap_second.gps_seconds - previous_offset
+            );
+            previous_offset = leap_second.gps_utc_offset;
+        }
+    }
+
+    #[test]
+    fn leap_lookup() {
+        // The binary search counts exactly the entries a linear scan of the table would, on both sides of each.
+        for (index, leap_second) in LEAP_SECONDS.iter().enumerate() {
+            let gps = leap_second * TO_NANO_INT;
+            assert_eq!(num_leaps(gps), index as i64 * TO_NANO_INT);
+            assert_eq!(num_leaps(gps + 1), (index as i64 + 1) * TO_NANO_INT);
+            let utc = gps - index as i64 * TO_NANO_INT;
+            assert_eq!(num_leaps_utc(utc - 1), index as i64 * TO_NANO_INT);
+            assert_eq!(num_leaps_utc(utc), (index as i64 + 1) * TO_NANO_INT);
+        }
+        assert_eq!(num_leaps(i64::MIN), 0);
+        assert_eq!(
+            num_leaps_utc(i64::MAX),
+            LEAP_SECONDS.len() as i64 * TO_NANO_INT
+        );
+    }
+
+    #[test]
+    fn leap_table_expiry() {
+        // The vendored list's `#@` line holds its expiry as seconds since 1900.
+        let list = include_str!("../data/leap-seconds.list");
+        let expires: i64 = list
+            .lines()
+            .find_map(|line| line.strip_prefix("#@"))
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        let epoch_1900 = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+        assert_eq!(
+            (LEAP_TABLE_EXPIRES - epoch_1900).num_seconds(),
+            expires,
+            "LEAP_TABLE_EXPIRES does not match data/leap-seconds.list"
+        );
+    }
+
     #[test]
     fn print_leap_seconds() {
         for leap_second in LEAP_SECONDS {
-            let date_time = DateTime::from_timestamp(leap_second + GPS_EPOCH, 0).unwrap();
+            let date_time =
+                DateTime::from_timestamp(leap_second + GPS_EPOCH / TO_NANO_INT, 0).unwrap();
             println!("{}", date_time.to_rfc3339());
         }
     }
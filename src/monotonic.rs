@@ -0,0 +1,105 @@
+//! Guarding streams of epochs against receiver time regressions.
+
+use crate::{Gpst, GpstError};
+
+/// What a [`MonotonicGuard`] does with an epoch earlier than the last one it passed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MonotonicPolicy {
+    /// Replace it with the last passed epoch.
+    Clamp,
+    /// Drop it.
+    Drop,
+    /// Fail with [`GpstError::NonMonotonic`].
+    Error,
+}
+
+/// Passes a stream of epochs on as non-decreasing, handling backwards steps according to a [`MonotonicPolicy`].
+#[derive(Debug, Clone)]
+pub struct MonotonicGuard {
+    policy: MonotonicPolicy,
+    last: Option<Gpst>,
+}
+
+impl MonotonicGuard {
+    /// A guard that has not seen any epochs yet.
+    pub fn new(policy: MonotonicPolicy) -> MonotonicGuard {
+        MonotonicGuard { policy, last: None }
+    }
+
+    /// The last epoch passed on.
+    pub fn last(&self) -> Option<Gpst> {
+        self.last
+    }
+
+    /// Check the next epoch, returning the epoch to pass on, or `None` if it was dropped.
+    pub fn check(&mut self, gpst: Gpst) -> Result<Option<Gpst>, GpstError> {
+        match self.last {
+            Some(last)
+                if gpst.wide_nanoseconds_since_epoch() < last.wide_nanoseconds_since_epoch() =>
+            {
+                match self.policy {
+                    MonotonicPolicy::Clamp => Ok(Some(last)),
+                    MonotonicPolicy::Drop => Ok(None),
+                    MonotonicPolicy::Error => Err(GpstError::NonMonotonic(last, gpst)),
+                }
+            }
+            _ => {
+                self.last = Some(gpst);
+                Ok(Some(gpst))
+            }
+        }
+    }
+
+    /// Guard a whole stream, yielding the epochs passed on.
+    pub fn guard<I>(mut self, epochs: I) -> impl Iterator<Item = Result<Gpst, GpstError>>
+    where
+        I: IntoIterator<Item = Gpst>,
+    {
+        epochs
+            .into_iter()
+            .filter_map(move |gpst| self.check(gpst).transpose())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, MonotonicGuard, MonotonicPolicy};
+
+    fn epochs() -> Vec<Gpst> {
+        [10.0, 11.0, 10.5, 11.0, 12.0]
+            .into_iter()
+            .map(|week_seconds| Gpst::normalized(1307, week_seconds))
+            .collect()
+    }
+
+    fn week_seconds(policy: MonotonicPolicy) -> Vec<f64> {
+        MonotonicGuard::new(policy)
+            .guard(epochs())
+            .map(|gpst| gpst.unwrap().week_seconds)
+            .collect()
+    }
+
+    #[test]
+    fn policies() {
+        assert_eq!(
+            week_seconds(MonotonicPolicy::Clamp),
+            [10.0, 11.0, 11.0, 11.0, 12.0]
+        );
+        assert_eq!(
+            week_seconds(MonotonicPolicy::Drop),
+            [10.0, 11.0, 11.0, 12.0]
+        );
+        let mut guard = MonotonicGuard::new(MonotonicPolicy::Error);
+        let results: Vec<_> = epochs().into_iter().map(|gpst| guard.check(gpst)).collect();
+        assert!(results[2].is_err());
+        assert!(results[3].is_ok());
+        assert_eq!(guard.last(), Some(Gpst::normalized(1307, 12.0)));
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let mut guard = MonotonicGuard::new(MonotonicPolicy::Error);
+        assert!(guard.check(Gpst::normalized(20001, 0.0)).is_ok());
+        assert!(guard.check(Gpst::normalized(20000, 0.0)).is_err());
+    }
+}
@@ -0,0 +1,319 @@
+//! GNSS signal and navigation message helpers built on [`Gpst`].
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    resolve_truncated_week, wide_gps_nanoseconds, Gpst, GpstError, GpstLike, LeapHandling,
+    WeekWidth, SECONDS_PER_WEEK, TO_NANO_FLOAT,
+};
+
+/// Speed of light in vacuum (m/s), as defined by IS-GPS-200.
+pub const SPEED_OF_LIGHT: f64 = 299792458.0;
+
+/// Z-count time of week unit in seconds.
+const Z_COUNT_UNIT: f64 = 1.5;
+/// Bits of the Z-count holding the time of week count.
+const Z_COUNT_TOW_BITS: u32 = 19;
+/// Number of Z-count units in a week.
+const Z_COUNTS_PER_WEEK: u32 = 403200;
+
+/// Subframe length and HOW time of week count unit in seconds.
+const SUBFRAME_SECONDS: f64 = 6.0;
+/// Number of HOW time of week counts in a week.
+const TOW_COUNTS_PER_WEEK: u32 = 100800;
+/// Subframes in an LNAV frame (30 s).
+const SUBFRAMES_PER_FRAME: u32 = 5;
+/// Frames, and so pages of subframes 4 and 5, in an LNAV superframe (12.5 minutes).
+const FRAMES_PER_SUPERFRAME: u32 = 25;
+
+/// Almanac reference time (toa) unit in seconds.
+const TOA_UNIT: f64 = 4096.0;
+/// Largest almanac reference time count, 602112 seconds into the week.
+const MAX_TOA: u8 = 147;
+
+/// One millisecond in seconds, and the matching integer units.
+const MILLISECOND: f64 = 1e-3;
+const NANOSECONDS_PER_MILLISECOND: i128 = 1_000_000;
+const MILLISECONDS_PER_WEEK: i128 = 604_800_000;
+
+/// Half a GPS week in seconds, the limit for week crossovers relative to a reference time.
+const HALF_WEEK: f64 = 302400.0;
+
+/// Time elapsed from a reference time of week (e.g. toe or toc) to `week_seconds`, accounting for beginning or end of
+/// week crossovers per IS-GPS-200 20.3.3.3.3.1.
+pub fn time_from_reference(week_seconds: f64, reference: f64) -> f64 {
+    let elapsed = week_seconds - reference;
+    if elapsed > HALF_WEEK {
+        elapsed - SECONDS_PER_WEEK
+    } else if elapsed < -HALF_WEEK {
+        elapsed + SECONDS_PER_WEEK
+    } else {
+        elapsed
+    }
+}
+
+/// Resolve the millisecond ambiguity of a sub-millisecond time of week (e.g. from code phase) against a coarse UTC
+/// time, as in coarse-time assisted GNSS. Optionally, adjust the coarse time for leap seconds.
+///
+/// `sub_ms_tow` is the time of week modulo one millisecond, in seconds. The whole milliseconds are taken from the
+/// coarse time, so the result is only correct while the coarse time is within ±0.5 ms of the actual (transmission)
+/// time; beyond that window it is off by whole milliseconds. The result is tagged with the leap handling, as by
+/// [`GpstLike::gpst`](crate::GpstLike::gpst).
+pub fn resolve_ms_ambiguity(
+    coarse: DateTime<Utc>,
+    sub_ms_tow: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<Gpst, GpstError> {
+    if !(0.0..MILLISECOND).contains(&sub_ms_tow) {
+        return Err(GpstError::SubMillisecond(sub_ms_tow));
+    }
+    let leap_seconds = leap_seconds.into();
+    let coarse_nanoseconds = wide_gps_nanoseconds(&coarse, leap_seconds)?;
+    let sub_ms_nanoseconds = (sub_ms_tow * TO_NANO_FLOAT).round() as i128;
+    let milliseconds = (coarse_nanoseconds - sub_ms_nanoseconds + NANOSECONDS_PER_MILLISECOND / 2)
+        .div_euclid(NANOSECONDS_PER_MILLISECOND);
+    Ok(Gpst::normalized(
+        milliseconds.div_euclid(MILLISECONDS_PER_WEEK) as i64,
+        milliseconds.rem_euclid(MILLISECONDS_PER_WEEK) as f64 * MILLISECOND + sub_ms_tow,
+    )
+    .with_leap_handling(leap_seconds))
+}
+
+/// Given the reception epoch of a signal and its pseudorange in meters, compute the satellite transmission epoch.
+/// The signal travel time is subtracted from the week seconds, borrowing from the week when crossing a week boundary.
+pub fn transmission_time(reception: Gpst, pseudorange_m: f64) -> Gpst {
+    let travel_time = pseudorange_m / SPEED_OF_LIGHT;
+    Gpst::normalized(reception.week, reception.week_seconds - travel_time).derived_from(&reception)
+}
+
+/// Apply the broadcast satellite clock polynomial (`af0`, `af1`, `af2`, referenced to `toc`) to a satellite's
+/// code phase time, returning the corrected GPS transmission epoch per IS-GPS-200 20.3.3.3.3.1.
+///
+/// The polynomial is evaluated at the uncorrected time, as the specification allows. The relativistic and group delay
+/// (TGD) terms depend on the orbit and signal, and are left to the caller.
+pub fn apply_sv_clock(gpst: Gpst, af0: f64, af1: f64, af2: f64, toc: f64) -> Gpst {
+    let elapsed = time_from_reference(gpst.week_seconds, toc);
+    let correction = af0 + af1 * elapsed + af2 * elapsed * elapsed;
+    Gpst::normalized(gpst.week, gpst.week_seconds - correction).derived_from(&gpst)
+}
+
+impl Gpst {
+    /// Encode as a 29-bit Z-count: the 10-bit week number in the upper bits and the time of week in 1.5 second
+    /// units in the lower 19 bits. Week seconds are truncated to the start of the enclosing 1.5 second unit.
+    pub fn to_z_count(&self) -> u32 {
+        let tow_count = ((self.week_seconds / Z_COUNT_UNIT) as u32).min(Z_COUNTS_PER_WEEK - 1);
+        ((self.truncated_week() as u32) << Z_COUNT_TOW_BITS) | tow_count
+    }
+
+    /// Decode a 29-bit Z-count, resolving the 10-bit week number relative to a reference date.
+    pub fn from_z_count(z_count: u32, reference: DateTime<Utc>) -> Result<Gpst, GpstError> {
+        let tow_count = z_count & ((1 << Z_COUNT_TOW_BITS) - 1);
+        if z_count >> (Z_COUNT_TOW_BITS + 10) != 0 || tow_count >= Z_COUNTS_PER_WEEK {
+            return Err(GpstError::ZCount(z_count));
+        }
+        let week10 = (z_count >> Z_COUNT_TOW_BITS) as u16;
+        let week = resolve_truncated_week(week10, WeekWidth::Gps10, reference.gpst(true)?.week)?;
+        Ok(Gpst::normalized(week, tow_count as f64 * Z_COUNT_UNIT))
+    }
+
+    /// Encode as the 17-bit time of week count carried in the handover word (HOW) of the subframe containing this
+    /// epoch. The count is in 6 second units and references the start of the *next* subframe, wrapping to 0 for the
+    /// last subframe of the week.
+    pub fn to_tow_count(&self) -> u32 {
+        ((self.week_seconds / SUBFRAME_SECONDS) as u32 + 1) % TOW_COUNTS_PER_WEEK
+    }
+
+    /// Decode a 17-bit HOW time of week count into the start of the subframe that carries it, i.e. 6 seconds before
+    /// the epoch the count references. `week` is the week of the referenced epoch, so a count of 0 yields the last
+    /// subframe of the previous week.
+    pub fn from_tow_count(week: i64, tow_count: u32) -> Result<Gpst, GpstError> {
+        if tow_count >= TOW_COUNTS_PER_WEEK {
+            return Err(GpstError::TowCount(tow_count));
+        }
+        Ok(Gpst::normalized(
+            week,
+            (tow_count as f64 - 1.0) * SUBFRAME_SECONDS,
+        ))
+    }
+
+    /// The LNAV subframe (1 through 5) being transmitted at this epoch. Frames of 5 six-second subframes start at the
+    /// beginning of the week.
+    pub fn lnav_subframe(&self) -> u8 {
+        (self.subframe_index() % SUBFRAMES_PER_FRAME + 1) as u8
+    }
+
+    /// The LNAV page (1 through 25) of subframes 4 and 5 being transmitted at this epoch, i.e. the frame within the
+    /// 12.5 minute superframe. Superframes are counted from the beginning of the week, as the week is not a whole
+    /// number of superframes.
+    pub fn lnav_page(&self) -> u8 {
+        (self.subframe_index() / SUBFRAMES_PER_FRAME % FRAMES_PER_SUPERFRAME + 1) as u8
+    }
+
+    /// Time of week of the next LNAV subframe boundary, the epoch the HOW of the current subframe references
+    /// ([`Gpst::to_tow_count`]). Wraps to 0 for the last subframe of the week.
+    pub fn next_subframe_tow(&self) -> f64 {
+        self.to_tow_count() as f64 * SUBFRAME_SECONDS
+    }
+
+    /// Index of the subframe being transmitted within the week.
+    fn subframe_index(&self) -> u32 {
+        ((self.week_seconds / SUBFRAME_SECONDS) as u32).min(TOW_COUNTS_PER_WEEK - 1)
+    }
+
+    /// Encode as an almanac reference time: the 8-bit time of applicability (toa) count in 4096 second units and the
+    /// 8-bit reference week (WNa), as `(toa, wna)`. Week seconds are truncated to the start of the enclosing 4096
+    /// second unit.
+    pub fn to_almanac_toa(&self) -> (u8, u8) {
+        let toa = ((self.week_seconds / TOA_UNIT) as u8).min(MAX_TOA);
+        (toa, self.truncated_week_as(WeekWidth::Almanac8) as u8)
+    }
+
+    /// Decode an almanac reference time (toa count in 4096 second units and 8-bit WNa), resolving the week relative
+    /// to a reference date, which must be within about 2.5 years of the almanac.
+    pub fn from_almanac_toa(toa: u8, wna: u8, reference: DateTime<Utc>) -> Result<Gpst, GpstError> {
+        if toa > MAX_TOA {
+            return Err(GpstError::AlmanacToa(toa));
+        }
+        let week =
+            resolve_truncated_week(wna as u16, WeekWidth::Almanac8, reference.gpst(true)?.week)?;
+        Ok(Gpst::normalized(week, toa as f64 * TOA_UNIT))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply_sv_clock, resolve_ms_ambiguity, time_from_reference, transmission_time, Gpst,
+        GpstLike, LeapHandling,
+    };
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn transmission() {
+        let reception = Gpst::normalized(1307, 480613.0);
+        let transmission = transmission_time(reception, 21_585_057.0);
+        assert_eq!(transmission.week, 1307);
+        assert!((transmission.week_seconds - 480612.928).abs() < 1e-9);
+    }
+
+    #[test]
+    fn transmission_week_wrap() {
+        let reception = Gpst::normalized(1307, 0.05);
+        let transmission = transmission_time(reception, 21_585_057.0);
+        assert_eq!(transmission.week, 1306);
+        assert!((transmission.week_seconds - 604799.978).abs() < 1e-9);
+        assert_eq!(
+            transmission.seconds,
+            1306.0 * 604800.0 + transmission.week_seconds
+        );
+    }
+
+    #[test]
+    fn ms_ambiguity() {
+        let coarse = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let resolved = resolve_ms_ambiguity(coarse, 0.000_25, true).unwrap();
+        assert_eq!(resolved.week, 1307);
+        assert!((resolved.week_seconds - 480613.000_25).abs() < 1e-9);
+        assert_eq!(resolved.leap_handling, Some(LeapHandling::Step));
+        // The coarse time is 0.4 ms late, so the millisecond before it is kept.
+        let late = coarse + TimeDelta::microseconds(1_300);
+        let resolved = resolve_ms_ambiguity(late, 0.000_9, true).unwrap();
+        assert!((resolved.week_seconds - 480613.000_9).abs() < 1e-9);
+        assert!(resolve_ms_ambiguity(coarse, 0.001, true).is_err());
+
+        // Past i64 nanoseconds since GPS Epoch.
+        let late = Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap();
+        let resolved = resolve_ms_ambiguity(late, 0.000_25, true).unwrap();
+        let expected = (late + TimeDelta::microseconds(250)).gpst(true).unwrap();
+        assert_eq!(resolved.week, expected.week);
+        assert!((resolved.week_seconds - expected.week_seconds).abs() < 1e-9);
+    }
+
+    #[test]
+    fn z_count() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        let z_count = gpst.to_z_count();
+        assert_eq!(z_count, (283 << 19) | 320408);
+        let reference = Utc.with_ymd_and_hms(2005, 6, 1, 0, 0, 0).unwrap();
+        let decoded = Gpst::from_z_count(z_count, reference).unwrap();
+        assert_eq!(decoded, Gpst::normalized(1307, 480612.0));
+        assert!(Gpst::from_z_count(1 << 29, reference).is_err());
+        assert!(Gpst::from_z_count(403200, reference).is_err());
+    }
+
+    #[test]
+    fn tow_count() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        assert_eq!(gpst.to_tow_count(), 80103);
+        assert_eq!(
+            Gpst::from_tow_count(1307, 80103).unwrap(),
+            Gpst::normalized(1307, 480612.0)
+        );
+        assert_eq!(Gpst::normalized(1307, 604795.0).to_tow_count(), 0);
+        assert_eq!(
+            Gpst::from_tow_count(1308, 0).unwrap(),
+            Gpst::normalized(1307, 604794.0)
+        );
+        assert!(Gpst::from_tow_count(1307, 100800).is_err());
+    }
+
+    #[test]
+    fn lnav_frame() {
+        let gpst = Gpst::normalized(1307, 480613.0);
+        // Subframe 80102 of the week, the 3rd of frame 16020, the 21st of its superframe.
+        assert_eq!(gpst.lnav_subframe(), 3);
+        assert_eq!(gpst.lnav_page(), 21);
+        assert_eq!(gpst.next_subframe_tow(), 480618.0);
+
+        let start = Gpst::normalized(1307, 0.0);
+        assert_eq!((start.lnav_subframe(), start.lnav_page()), (1, 1));
+        let frame = Gpst::normalized(1307, 749.9);
+        assert_eq!((frame.lnav_subframe(), frame.lnav_page()), (5, 25));
+        let end = Gpst::normalized(1307, 604799.5);
+        assert_eq!(end.lnav_subframe(), 5);
+        assert_eq!(end.next_subframe_tow(), 0.0);
+    }
+
+    #[test]
+    fn almanac_toa() {
+        let gpst = Gpst::normalized(2280, 319488.0 + 100.0);
+        assert_eq!(gpst.to_almanac_toa(), (78, 232));
+        assert_eq!(
+            Gpst::normalized(2280, 604799.0).to_almanac_toa(),
+            (147, 232)
+        );
+
+        let reference = Utc.with_ymd_and_hms(2023, 9, 20, 0, 0, 0).unwrap();
+        assert_eq!(
+            Gpst::from_almanac_toa(78, 232, reference).unwrap(),
+            Gpst::normalized(2280, 319488.0)
+        );
+        // WNa 255 just before a reference in week 2304 (WNa 0) is the week before the rollover.
+        let reference = Utc.with_ymd_and_hms(2024, 3, 3, 12, 0, 0).unwrap();
+        assert_eq!(
+            Gpst::from_almanac_toa(0, 255, reference).unwrap(),
+            Gpst::normalized(2303, 0.0)
+        );
+        assert!(Gpst::from_almanac_toa(148, 0, reference).is_err());
+    }
+
+    #[test]
+    fn reference_crossover() {
+        assert_eq!(time_from_reference(100.0, 604000.0), 900.0);
+        assert_eq!(time_from_reference(604000.0, 100.0), -900.0);
+        assert_eq!(time_from_reference(7300.0, 7200.0), 100.0);
+    }
+
+    #[test]
+    fn sv_clock() {
+        let gpst = Gpst::normalized(1307, 10.0);
+        let corrected = apply_sv_clock(gpst, 1e-4, 1e-11, 0.0, 604790.0);
+        assert_eq!(corrected.week, 1307);
+        assert!((corrected.week_seconds - (10.0 - 1e-4 - 1e-11 * 20.0)).abs() < 1e-12);
+
+        let gpst = Gpst::normalized(1307, 0.0);
+        let corrected = apply_sv_clock(gpst, 1e-3, 0.0, 0.0, 0.0);
+        assert_eq!(corrected.week, 1306);
+        assert!((corrected.week_seconds - (604800.0 - 1e-3)).abs() < 1e-9);
+    }
+}
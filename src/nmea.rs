@@ -0,0 +1,148 @@
+//! UTC time extraction from NMEA 0183 `ZDA`, `RMC` and `GGA` sentences, for any talker (`GP`, `GN`, ...).
+
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+use crate::{Gpst, GpstError, GpstLike, LeapHandling, ParseInput};
+
+/// Split a sentence into its comma separated fields, verifying the checksum when one is present.
+fn fields(sentence: &str) -> Option<Vec<&str>> {
+    let body = sentence.trim().strip_prefix(['$', '!'])?;
+    let body = match body.split_once('*') {
+        Some((body, checksum)) => {
+            let expected = u8::from_str_radix(checksum, 16).ok()?;
+            if body.bytes().fold(0, |sum, byte| sum ^ byte) != expected {
+                return None;
+            }
+            body
+        }
+        None => body,
+    };
+    Some(body.split(',').collect())
+}
+
+/// Whether a field is all ASCII digits, so it can be sliced by byte offsets.
+fn is_digits(field: &str) -> bool {
+    field.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Parse `hhmmss` with optional fractional seconds.
+fn parse_time(field: &str) -> Option<NaiveTime> {
+    let (whole, fraction) = field.split_once('.').unwrap_or((field, ""));
+    if whole.len() != 6 || fraction.len() > 9 || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+    let nanoseconds = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<9}").parse().ok()?
+    };
+    NaiveTime::from_hms_nano_opt(
+        whole[0..2].parse().ok()?,
+        whole[2..4].parse().ok()?,
+        whole[4..6].parse().ok()?,
+        nanoseconds,
+    )
+}
+
+/// Parse an `RMC` `ddmmyy` date. Two digit years from 80 are in the 1900s, as GPS did not exist before 1980.
+fn parse_short_date(field: &str) -> Option<NaiveDate> {
+    if field.len() != 6 || !is_digits(field) {
+        return None;
+    }
+    let year: i32 = field[4..6].parse().ok()?;
+    let year = if year >= 80 { 1900 + year } else { 2000 + year };
+    NaiveDate::from_ymd_opt(year, field[2..4].parse().ok()?, field[0..2].parse().ok()?)
+}
+
+/// The instant closest to `reference` that has the given time of day.
+fn closest_to(time: NaiveTime, reference: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let date = reference.date_naive();
+    [date.pred_opt()?, date, date.succ_opt()?]
+        .into_iter()
+        .map(|date| date.and_time(time).and_utc())
+        .min_by_key(|candidate| (*candidate - reference).abs())
+}
+
+/// Extract the UTC time of a `ZDA`, `RMC` or `GGA` sentence.
+///
+/// `GGA` sentences carry no date, so the instant with that time of day closest to `reference` is used, which is
+/// correct while the reference is within 12 hours of the sentence. The reference is ignored for the other sentences.
+pub fn utc_time(sentence: &str, reference: DateTime<Utc>) -> Result<DateTime<Utc>, GpstError> {
+    let error = || GpstError::Parse(ParseInput::new("NMEA sentence"));
+    let fields = fields(sentence).ok_or_else(error)?;
+    let kind = fields[0].get(2..).ok_or_else(error)?;
+    let field = |index: usize| fields.get(index).copied().ok_or_else(error);
+    let time = parse_time(field(1)?).ok_or_else(error)?;
+    let date_time = match kind {
+        "ZDA" => {
+            let date = NaiveDate::from_ymd_opt(
+                field(4)?.parse().map_err(|_| error())?,
+                field(3)?.parse().map_err(|_| error())?,
+                field(2)?.parse().map_err(|_| error())?,
+            );
+            date.map(|date| date.and_time(time).and_utc())
+        }
+        "RMC" => parse_short_date(field(9)?).map(|date| date.and_time(time).and_utc()),
+        "GGA" => closest_to(time, reference),
+        _ => None,
+    };
+    date_time.ok_or_else(error)
+}
+
+/// Extract the time of a `ZDA`, `RMC` or `GGA` sentence as GPS Time (GPST). Optionally, adjust for leap seconds.
+/// See [`utc_time`] for how `reference` resolves the date of `GGA` sentences.
+pub fn gpst(
+    sentence: &str,
+    reference: DateTime<Utc>,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<Gpst, GpstError> {
+    utc_time(sentence, reference)?.gpst(leap_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{nmea, Gpst};
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn zda_rmc() {
+        let expected = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let reference = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+        let zda = "$GPZDA,133000.00,28,01,2005,00,00*6B";
+        assert_eq!(nmea::utc_time(zda, reference).unwrap(), expected);
+        let rmc = "$GNRMC,133000.00,A,4807.038,N,01131.000,E,0.0,0.0,280105,,,A";
+        assert_eq!(nmea::utc_time(rmc, reference).unwrap(), expected);
+        assert_eq!(
+            nmea::gpst(rmc, reference, true).unwrap(),
+            Gpst::normalized(1307, 480613.0).with_leap_handling(true)
+        );
+        assert!(nmea::utc_time("$GPZDA,133000.00,28,01,2005,00,00*00", reference).is_err());
+        assert!(nmea::utc_time("$GPGSV,3,1,11", reference).is_err());
+    }
+
+    #[test]
+    fn gga_date() {
+        let gga = "$GPGGA,235959.50,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,";
+        let reference = Utc.with_ymd_and_hms(2005, 1, 29, 0, 0, 5).unwrap();
+        assert_eq!(
+            nmea::utc_time(gga, reference).unwrap(),
+            Utc.with_ymd_and_hms(2005, 1, 28, 23, 59, 59).unwrap() + TimeDelta::milliseconds(500)
+        );
+    }
+
+    #[test]
+    fn non_ascii() {
+        let reference = Utc.with_ymd_and_hms(2005, 1, 29, 0, 0, 5).unwrap();
+        for sentence in [
+            "$GPGGA,1é300,x",
+            "$GPGGA,13300é,x",
+            "$GPGGA,133000.é,x",
+            "$GPGGA,+13000,x",
+            "$GNRMC,133000.00,A,4807.038,N,01131.000,E,0.0,0.0,2é015,,,A",
+            "$GNRMC,133000.00,A,4807.038,N,01131.000,E,0.0,0.0,+80105,,,A",
+            "$Gé",
+        ] {
+            assert!(nmea::utc_time(sentence, reference).is_err(), "{sentence}");
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! Differential oracle against hifitime, enabled with the dev-only `hifitime-oracle` feature.
+//!
+//! Downstream users migrating to or from hifitime can feed their own epochs through [`check_against_hifitime`].
+
+use chrono::{DateTime, Utc};
+
+use crate::hifitime_interop::epoch_from_utc;
+use crate::{from_wide_gps_nanoseconds, wide_gps_nanoseconds, GpstError};
+
+/// Largest disagreement with hifitime tolerated by the oracle, in nanoseconds.
+pub const ORACLE_TOLERANCE_NANOSECONDS: i128 = 1;
+
+/// A disagreement between this crate and hifitime beyond [`ORACLE_TOLERANCE_NANOSECONDS`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum OracleMismatch {
+    /// This crate could not convert the epoch at all.
+    Conversion(DateTime<Utc>, String),
+    /// UTC to GPST disagrees: the epoch, then nanoseconds since GPS Epoch from this crate and from hifitime.
+    ToGpst(DateTime<Utc>, i128, i128),
+    /// GPST to UTC does not round-trip: the epoch, then the UTC instant this crate converted hifitime's GPST to.
+    FromGpst(DateTime<Utc>, DateTime<Utc>),
+}
+
+impl From<(DateTime<Utc>, GpstError)> for OracleMismatch {
+    fn from((date_time, error): (DateTime<Utc>, GpstError)) -> Self {
+        OracleMismatch::Conversion(date_time, error.to_string())
+    }
+}
+
+/// Convert a UTC instant to GPST and back with leap seconds, checking both directions against hifitime.
+pub fn check_against_hifitime(date_time: &DateTime<Utc>) -> Result<(), OracleMismatch> {
+    let date_time = *date_time;
+    let nanoseconds = wide_gps_nanoseconds(&date_time, true).map_err(|e| (date_time, e))?;
+    let expected = epoch_from_utc(&date_time)
+        .to_gpst_duration()
+        .total_nanoseconds();
+    if (nanoseconds - expected).abs() > ORACLE_TOLERANCE_NANOSECONDS {
+        return Err(OracleMismatch::ToGpst(date_time, nanoseconds, expected));
+    }
+    let round_trip = from_wide_gps_nanoseconds(expected, true).map_err(|e| (date_time, e))?;
+    let difference = (round_trip - date_time)
+        .num_nanoseconds()
+        .unwrap_or(i64::MAX);
+    if difference.unsigned_abs() as i128 > ORACLE_TOLERANCE_NANOSECONDS {
+        return Err(OracleMismatch::FromGpst(date_time, round_trip));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{check_against_hifitime, GPS_EPOCH, LEAP_SECONDS, TO_NANO_INT};
+    use chrono::DateTime;
+
+    /// xorshift64*, so the fuzz run is reproducible without an extra dependency.
+    fn next(state: &mut u64) -> u64 {
+        *state ^= *state >> 12;
+        *state ^= *state << 25;
+        *state ^= *state >> 27;
+        state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+
+    #[test]
+    fn random_epochs() {
+        // From GPS Epoch to the end of the i64 nanosecond range, in 2262.
+        let (start, end) = (GPS_EPOCH, i64::MAX - TO_NANO_INT);
+        let mut state = 0x9E3779B97F4A7C15;
+        for _ in 0..20_000 {
+            let nanoseconds = start + (next(&mut state) % (end - start) as u64) as i64;
+            let date_time = DateTime::from_timestamp_nanos(nanoseconds);
+            assert_eq!(check_against_hifitime(&date_time), Ok(()));
+        }
+    }
+
+    #[test]
+    fn leap_epochs() {
+        let mut state = 0x2545F4914F6CDD1D;
+        for (index, leap_second) in LEAP_SECONDS.iter().enumerate() {
+            let event = GPS_EPOCH + (leap_second - index as i64) * TO_NANO_INT;
+            for _ in 0..500 {
+                let offset = (next(&mut state) % 4_000_000_000) as i64 - 2_000_000_000;
+                let date_time = DateTime::from_timestamp_nanos(event + offset);
+                assert_eq!(check_against_hifitime(&date_time), Ok(()));
+            }
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Python bindings, enabled with the `python` feature, so Python code runs the same conversions as Rust. Build the
+//! extension module with [maturin](https://www.maturin.rs/) (`maturin develop --features python,pyo3/extension-module`),
+//! then
+//!
+//! ```python
+//! import datetime, chrono_gpst
+//! week, tow = chrono_gpst.to_gpst(datetime.datetime(2005, 1, 28, 13, 30, tzinfo=datetime.timezone.utc))
+//! chrono_gpst.from_gpst(week, tow)  # datetime.datetime(2005, 1, 28, 13, 30, tzinfo=datetime.timezone.utc)
+//! ```
+
+use chrono::{DateTime, FixedOffset, Utc};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{GpstError, GpstLike};
+
+fn value_error(error: GpstError) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Convert a timezone-aware `datetime.datetime` to a `(week, tow)` tuple. Optionally, adjust for leap seconds.
+#[pyfunction]
+#[pyo3(signature = (datetime, leap = true))]
+fn to_gpst(datetime: DateTime<FixedOffset>, leap: bool) -> PyResult<(i64, f64)> {
+    let gpst = datetime
+        .with_timezone(&Utc)
+        .gpst(leap)
+        .map_err(value_error)?;
+    Ok((gpst.week, gpst.week_seconds))
+}
+
+/// Convert a week and time of week to a `datetime.datetime` in UTC. Optionally, adjust for leap seconds.
+#[pyfunction]
+#[pyo3(name = "from_gpst", signature = (week, tow, leap = true))]
+fn from_gpst_py(week: i64, tow: f64, leap: bool) -> PyResult<DateTime<Utc>> {
+    crate::from_gpst(week, tow, leap).map_err(value_error)
+}
+
+/// The `chrono_gpst` Python module.
+#[pymodule]
+fn chrono_gpst(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(to_gpst, module)?)?;
+    module.add_function(wrap_pyfunction!(from_gpst_py, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn module() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "chrono_gpst").unwrap();
+            super::chrono_gpst(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("chrono_gpst", module).unwrap();
+            py.run(
+                cr#"
+import datetime
+utc = datetime.datetime(2005, 1, 28, 13, 30, tzinfo=datetime.timezone.utc)
+assert chrono_gpst.to_gpst(utc) == (1307, 480613.0)
+assert chrono_gpst.to_gpst(utc, leap=False) == (1307, 480600.0)
+paris = datetime.datetime(2005, 1, 28, 14, 30, tzinfo=datetime.timezone(datetime.timedelta(hours=1)))
+assert chrono_gpst.to_gpst(paris) == (1307, 480613.0)
+assert chrono_gpst.from_gpst(1307, 480613.0) == utc
+assert chrono_gpst.from_gpst(1307, 480600.0, leap=False) == utc
+try:
+    chrono_gpst.from_gpst(1307, 604800.0)
+    raise AssertionError
+except ValueError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,150 @@
+//! Half-open intervals of GPS Time.
+
+use chrono::TimeDelta;
+
+use crate::{clamp_to_i64, Gpst, GpstEpochs, GpstError, NANOSECONDS_PER_WEEK};
+
+/// A half-open interval of GPS Time, from `start` (inclusive) to `end` (exclusive). Empty when `end` is not after
+/// `start`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GpstRange {
+    /// First epoch in the range.
+    pub start: Gpst,
+    /// First epoch after the range.
+    pub end: Gpst,
+}
+
+impl GpstRange {
+    /// A range from `start` (inclusive) to `end` (exclusive).
+    pub fn new(start: Gpst, end: Gpst) -> GpstRange {
+        GpstRange { start, end }
+    }
+
+    /// Whether the range contains no epochs.
+    pub fn is_empty(&self) -> bool {
+        self.end.wide_nanoseconds_since_epoch() <= self.start.wide_nanoseconds_since_epoch()
+    }
+
+    /// Whether an epoch falls within the range.
+    pub fn contains(&self, gpst: &Gpst) -> bool {
+        let nanoseconds = gpst.wide_nanoseconds_since_epoch();
+        self.start.wide_nanoseconds_since_epoch() <= nanoseconds
+            && nanoseconds < self.end.wide_nanoseconds_since_epoch()
+    }
+
+    /// The overlap of two ranges. `None` if they do not overlap.
+    pub fn intersect(&self, other: &GpstRange) -> Option<GpstRange> {
+        let start = self
+            .start
+            .wide_nanoseconds_since_epoch()
+            .max(other.start.wide_nanoseconds_since_epoch());
+        let end = self
+            .end
+            .wide_nanoseconds_since_epoch()
+            .min(other.end.wide_nanoseconds_since_epoch());
+        (start < end).then(|| {
+            GpstRange::new(
+                Gpst::from_wide_nanoseconds(start).derived_from(&self.start),
+                Gpst::from_wide_nanoseconds(end).derived_from(&self.end),
+            )
+        })
+    }
+
+    /// Length of the range, zero when empty. Saturates for ranges longer than `i64` nanoseconds (about 292 years).
+    pub fn duration(&self) -> TimeDelta {
+        let nanoseconds =
+            self.end.wide_nanoseconds_since_epoch() - self.start.wide_nanoseconds_since_epoch();
+        TimeDelta::nanoseconds(clamp_to_i64(nanoseconds.max(0)))
+    }
+
+    /// Split the range at GPS week boundaries, yielding one range per (partial) week in order.
+    pub fn split_by_week(&self) -> Vec<GpstRange> {
+        let week_nanoseconds = NANOSECONDS_PER_WEEK as i128;
+        let end = self.end.wide_nanoseconds_since_epoch();
+        let mut start = self.start.wide_nanoseconds_since_epoch();
+        let mut ranges = Vec::new();
+        while start < end {
+            let week_end = (start.div_euclid(week_nanoseconds) + 1) * week_nanoseconds;
+            let split = week_end.min(end);
+            ranges.push(GpstRange::new(
+                Gpst::from_wide_nanoseconds(start).derived_from(&self.start),
+                Gpst::from_wide_nanoseconds(split).derived_from(&self.start),
+            ));
+            start = split;
+        }
+        ranges
+    }
+
+    /// Epochs within the range aligned to a sampling interval, as [`GpstEpochs::new`].
+    pub fn epochs(&self, interval: TimeDelta) -> Result<GpstEpochs, GpstError> {
+        GpstEpochs::new(self.start, self.end, interval)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstRange};
+    use chrono::TimeDelta;
+
+    #[test]
+    fn interval() {
+        let range = GpstRange::new(
+            Gpst::normalized(1307, 600000.0),
+            Gpst::normalized(1308, 3600.0),
+        );
+        assert!(range.contains(&Gpst::normalized(1308, 0.0)));
+        assert!(!range.contains(&range.end));
+        assert_eq!(range.duration(), TimeDelta::seconds(8400));
+        assert_eq!(range.epochs(TimeDelta::hours(1)).unwrap().len(), 2);
+
+        let other = GpstRange::new(Gpst::normalized(1308, 1800.0), Gpst::normalized(1309, 0.0));
+        assert_eq!(
+            range.intersect(&other),
+            Some(GpstRange::new(Gpst::normalized(1308, 1800.0), range.end))
+        );
+        let later = GpstRange::new(range.end, Gpst::normalized(1309, 0.0));
+        assert_eq!(range.intersect(&later), None);
+        assert!(GpstRange::new(range.end, range.start).is_empty());
+        assert_eq!(
+            GpstRange::new(range.end, range.start).duration(),
+            TimeDelta::zero()
+        );
+    }
+
+    #[test]
+    fn split() {
+        let range = GpstRange::new(
+            Gpst::normalized(1307, 600000.0),
+            Gpst::normalized(1309, 10.0),
+        );
+        let weeks = range.split_by_week();
+        assert_eq!(
+            weeks,
+            vec![
+                GpstRange::new(range.start, Gpst::normalized(1308, 0.0)),
+                GpstRange::new(Gpst::normalized(1308, 0.0), Gpst::normalized(1309, 0.0)),
+                GpstRange::new(Gpst::normalized(1309, 0.0), range.end),
+            ]
+        );
+        assert!(GpstRange::new(range.end, range.start)
+            .split_by_week()
+            .is_empty());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let range = GpstRange::new(
+            Gpst::normalized(20000, 604000.0),
+            Gpst::normalized(20001, 0.0),
+        );
+        assert!(!range.is_empty());
+        assert!(range.contains(&Gpst::normalized(20000, 604799.0)));
+        assert_eq!(range.duration(), TimeDelta::seconds(800));
+        assert_eq!(range.split_by_week(), vec![range]);
+        let other = GpstRange::new(Gpst::normalized(20000, 604400.0), range.end);
+        assert_eq!(range.intersect(&other), Some(other));
+
+        let long = GpstRange::new(Gpst::normalized(0, 0.0), Gpst::normalized(20001, 0.0));
+        assert_eq!(long.duration(), TimeDelta::nanoseconds(i64::MAX));
+    }
+}
@@ -0,0 +1,242 @@
+//! Resampling of `(Gpst, f64)` series onto another set of epochs, e.g. precise products onto observation epochs.
+
+use crate::{Gpst, GpstError, TO_NANO_FLOAT};
+
+/// Interpolation method between the samples bracketing a grid epoch.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ResampleMethod {
+    /// Straight line between the bracketing samples.
+    Linear,
+    /// Natural cubic spline through the samples. Runs separated by a gap are fitted independently.
+    Spline,
+    /// Value of the latest sample at or before the grid epoch (zero-order hold).
+    Hold,
+}
+
+/// What to do with grid epochs whose bracketing samples are too far apart.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GapPolicy {
+    /// Interpolate across gaps of any length.
+    Bridge,
+    /// Leave out grid epochs inside a gap longer than `max_gap` seconds.
+    Skip {
+        /// Longest time between samples to interpolate across, in seconds.
+        max_gap: f64,
+    },
+    /// Fail on the first grid epoch inside a gap longer than `max_gap` seconds.
+    Fail {
+        /// Longest time between samples to interpolate across, in seconds.
+        max_gap: f64,
+    },
+}
+
+impl GapPolicy {
+    fn max_gap(&self) -> f64 {
+        match self {
+            GapPolicy::Bridge => f64::INFINITY,
+            GapPolicy::Skip { max_gap } | GapPolicy::Fail { max_gap } => *max_gap,
+        }
+    }
+}
+
+/// Resample a series of `(epoch, value)` pairs, in strictly increasing epoch order, onto grid epochs.
+///
+/// Grid epochs outside of the series (no extrapolation) and, with [`GapPolicy::Skip`], inside gaps are left out, so
+/// the result pairs each remaining grid epoch with its value. Grid epochs matching a sample take its value as is.
+pub fn resample(
+    series: &[(Gpst, f64)],
+    grid: &[Gpst],
+    method: ResampleMethod,
+    gap_policy: GapPolicy,
+) -> Result<Vec<(Gpst, f64)>, GpstError> {
+    for pair in series.windows(2) {
+        if pair[1].0.wide_nanoseconds_since_epoch() <= pair[0].0.wide_nanoseconds_since_epoch() {
+            return Err(GpstError::NonMonotonic(pair[0].0, pair[1].0));
+        }
+    }
+    let Some(&(first, _)) = series.first() else {
+        return Ok(Vec::new());
+    };
+    // Seconds relative to the first sample, so large epochs do not lose precision.
+    let origin = first.wide_nanoseconds_since_epoch();
+    let offset =
+        |gpst: &Gpst| (gpst.wide_nanoseconds_since_epoch() - origin) as f64 / TO_NANO_FLOAT;
+    let times: Vec<f64> = series.iter().map(|(gpst, _)| offset(gpst)).collect();
+    let values: Vec<f64> = series.iter().map(|(_, value)| *value).collect();
+    let max_gap = gap_policy.max_gap();
+    let curvatures = match method {
+        ResampleMethod::Spline => spline_curvatures(&times, &values, max_gap),
+        ResampleMethod::Linear | ResampleMethod::Hold => Vec::new(),
+    };
+
+    let mut resampled = Vec::with_capacity(grid.len());
+    for epoch in grid {
+        let time = offset(epoch);
+        let after = times.partition_point(|sample| *sample <= time);
+        if after == 0 {
+            continue;
+        }
+        let before = after - 1;
+        if times[before] == time {
+            resampled.push((*epoch, values[before]));
+            continue;
+        }
+        if after == times.len() {
+            continue;
+        }
+        if times[after] - times[before] > max_gap {
+            match gap_policy {
+                GapPolicy::Fail { .. } => {
+                    return Err(GpstError::Gap(series[before].0, series[after].0))
+                }
+                GapPolicy::Bridge | GapPolicy::Skip { .. } => continue,
+            }
+        }
+        let (t0, t1, v0, v1) = (times[before], times[after], values[before], values[after]);
+        let value = match method {
+            ResampleMethod::Hold => v0,
+            ResampleMethod::Linear => v0 + (v1 - v0) * (time - t0) / (t1 - t0),
+            ResampleMethod::Spline => {
+                let (m0, m1) = (curvatures[before], curvatures[after]);
+                let h = t1 - t0;
+                let (a, b) = ((t1 - time) / h, (time - t0) / h);
+                a * v0 + b * v1 + ((a * a * a - a) * m0 + (b * b * b - b) * m1) * h * h / 6.0
+            }
+        };
+        resampled.push((*epoch, value));
+    }
+    Ok(resampled)
+}
+
+/// Second derivatives of a natural cubic spline at each sample, fitted separately to each run of samples that are
+/// at most `max_gap` seconds apart.
+fn spline_curvatures(times: &[f64], values: &[f64], max_gap: f64) -> Vec<f64> {
+    let mut curvatures = vec![0.0; times.len()];
+    let mut start = 0;
+    for end in 1..=times.len() {
+        if end == times.len() || times[end] - times[end - 1] > max_gap {
+            solve_natural_spline(
+                &times[start..end],
+                &values[start..end],
+                &mut curvatures[start..end],
+            );
+            start = end;
+        }
+    }
+    curvatures
+}
+
+/// Solve the tridiagonal system of a natural cubic spline with the Thomas algorithm.
+fn solve_natural_spline(times: &[f64], values: &[f64], curvatures: &mut [f64]) {
+    let n = times.len();
+    if n < 3 {
+        return;
+    }
+    let mut diagonal = vec![0.0; n];
+    let mut rhs = vec![0.0; n];
+    for i in 1..n - 1 {
+        let (h0, h1) = (times[i] - times[i - 1], times[i + 1] - times[i]);
+        diagonal[i] = 2.0 * (h0 + h1);
+        rhs[i] = 6.0 * ((values[i + 1] - values[i]) / h1 - (values[i] - values[i - 1]) / h0);
+        if i > 1 {
+            let factor = h0 / diagonal[i - 1];
+            diagonal[i] -= factor * h0;
+            rhs[i] -= factor * rhs[i - 1];
+        }
+    }
+    for i in (1..n - 1).rev() {
+        let h1 = times[i + 1] - times[i];
+        curvatures[i] = (rhs[i] - h1 * curvatures[i + 1]) / diagonal[i];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{resample, GapPolicy, Gpst, GpstError, ResampleMethod};
+
+    fn epoch(week_seconds: f64) -> Gpst {
+        Gpst::normalized(2280, week_seconds)
+    }
+
+    #[test]
+    fn linear_and_hold() {
+        let series = [(epoch(0.0), 0.0), (epoch(30.0), 3.0), (epoch(60.0), 9.0)];
+        let grid = [
+            epoch(-10.0),
+            epoch(0.0),
+            epoch(15.0),
+            epoch(45.0),
+            epoch(70.0),
+        ];
+        let linear = resample(&series, &grid, ResampleMethod::Linear, GapPolicy::Bridge).unwrap();
+        assert_eq!(
+            linear,
+            vec![(epoch(0.0), 0.0), (epoch(15.0), 1.5), (epoch(45.0), 6.0)]
+        );
+        let hold = resample(&series, &grid, ResampleMethod::Hold, GapPolicy::Bridge).unwrap();
+        assert_eq!(
+            hold,
+            vec![(epoch(0.0), 0.0), (epoch(15.0), 0.0), (epoch(45.0), 3.0)]
+        );
+    }
+
+    #[test]
+    fn spline() {
+        // A natural cubic spline reproduces straight lines exactly, and is smooth through curves.
+        let line: Vec<_> = (0..5).map(|i| (epoch(i as f64 * 30.0), i as f64)).collect();
+        let grid = [epoch(45.0), epoch(100.0)];
+        let resampled = resample(&line, &grid, ResampleMethod::Spline, GapPolicy::Bridge).unwrap();
+        assert!((resampled[0].1 - 1.5).abs() < 1e-12);
+        assert!((resampled[1].1 - 100.0 / 30.0).abs() < 1e-12);
+
+        let curve: Vec<_> = (0..9)
+            .map(|i| (epoch(i as f64 * 900.0), (i as f64 * 0.2).sin()))
+            .collect();
+        let resampled = resample(
+            &curve,
+            &[epoch(3150.0)],
+            ResampleMethod::Spline,
+            GapPolicy::Bridge,
+        )
+        .unwrap();
+        assert!((resampled[0].1 - 0.7_f64.sin()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn gaps() {
+        let series = [(epoch(0.0), 0.0), (epoch(30.0), 3.0), (epoch(300.0), 30.0)];
+        let grid = [epoch(15.0), epoch(100.0)];
+        let skip = GapPolicy::Skip { max_gap: 60.0 };
+        let resampled = resample(&series, &grid, ResampleMethod::Linear, skip).unwrap();
+        assert_eq!(resampled, vec![(epoch(15.0), 1.5)]);
+        let fail = GapPolicy::Fail { max_gap: 60.0 };
+        assert!(matches!(
+            resample(&series, &grid, ResampleMethod::Spline, fail),
+            Err(GpstError::Gap(..))
+        ));
+        let unsorted = [(epoch(30.0), 3.0), (epoch(0.0), 0.0)];
+        assert!(matches!(
+            resample(&unsorted, &grid, ResampleMethod::Linear, GapPolicy::Bridge),
+            Err(GpstError::NonMonotonic(..))
+        ));
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let epoch = |week_seconds| Gpst::normalized(20000, week_seconds);
+        let series = [(epoch(0.0), 0.0), (epoch(30.0), 3.0)];
+        let resampled = resample(
+            &series,
+            &[epoch(15.0)],
+            ResampleMethod::Linear,
+            GapPolicy::Bridge,
+        )
+        .unwrap();
+        assert_eq!(resampled, vec![(epoch(15.0), 1.5)]);
+        let unsorted = [(epoch(30.0), 3.0), (epoch(0.0), 0.0)];
+        assert!(matches!(
+            resample(&unsorted, &[], ResampleMethod::Linear, GapPolicy::Bridge),
+            Err(GpstError::NonMonotonic(..))
+        ));
+    }
+}
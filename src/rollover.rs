@@ -0,0 +1,260 @@
+//! Week number rollover disambiguation for truncated broadcast week numbers.
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    clamp_to_i64, Gpst, GpstError, GpstLike, TimeInput, TimeScale, NANOSECONDS_PER_WEEK,
+    TO_NANO_INT,
+};
+
+/// GPS week in which Galileo System Time (GST) week 0 starts, on 1999-08-22. GST is aligned with GPS Time, so GST
+/// weeks start together with GPS weeks.
+pub const GALILEO_EPOCH_WEEK: i64 = 1024;
+
+/// GPS week in which BeiDou Time (BDT) week 0 starts, on 2006-01-01. BDT runs 14 seconds behind GPS Time, so BDT
+/// weeks start 14 seconds into a GPS week.
+pub const BEIDOU_EPOCH_WEEK: i64 = 1356;
+
+/// Bit width of a truncated, broadcast week number.
+///
+/// Each width counts weeks on its own system's scale, see [`WeekWidth::week_of`] and [`WeekWidth::gps_week`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WeekWidth {
+    /// 8-bit almanac reference week (WNa), on the GPS week count.
+    Almanac8,
+    /// 10-bit GPS legacy navigation message week, on the GPS week count.
+    Gps10,
+    /// 12-bit Galileo week, on the GST week count: GPS week − [`GALILEO_EPOCH_WEEK`]. Resolve it against a GST
+    /// reference week, not a GPS one.
+    Galileo12,
+    /// 13-bit BeiDou week, on the BDT week count: GPS week − [`BEIDOU_EPOCH_WEEK`], 14 seconds late. Resolve it
+    /// against a BDT reference week, not a GPS one.
+    Beidou13,
+}
+
+impl WeekWidth {
+    /// Number of weeks before a truncated week number rolls over.
+    pub fn modulus(&self) -> i64 {
+        match self {
+            WeekWidth::Almanac8 => 1 << 8,
+            WeekWidth::Gps10 => 1 << 10,
+            WeekWidth::Galileo12 => 1 << 12,
+            WeekWidth::Beidou13 => 1 << 13,
+        }
+    }
+
+    /// The full week of a (leap second adjusted) GPS epoch on this width's week count, e.g. as the reference week
+    /// of [`resolve_truncated_week`]. Saturates for garbage weeks.
+    pub fn week_of(&self, gpst: &Gpst) -> i64 {
+        let nanoseconds = gpst.wide_nanoseconds_since_epoch();
+        let (scale, epoch_week) = match self {
+            WeekWidth::Almanac8 | WeekWidth::Gps10 => (TimeScale::Gps, 0),
+            WeekWidth::Galileo12 => (TimeScale::Galileo, GALILEO_EPOCH_WEEK),
+            WeekWidth::Beidou13 => (TimeScale::Beidou, BEIDOU_EPOCH_WEEK),
+        };
+        let seconds_behind = scale.seconds_behind_gps().unwrap_or_default();
+        let week = (nanoseconds - (seconds_behind * TO_NANO_INT) as i128)
+            .div_euclid(NANOSECONDS_PER_WEEK as i128)
+            - epoch_week as i128;
+        clamp_to_i64(week)
+    }
+
+    /// The GPS week in which a full week on this width's week count starts, the inverse of [`WeekWidth::week_of`].
+    /// A BDT week starts 14 seconds into the returned GPS week. Saturates for garbage weeks.
+    pub fn gps_week(&self, week: i64) -> i64 {
+        match self {
+            WeekWidth::Almanac8 | WeekWidth::Gps10 => week,
+            WeekWidth::Galileo12 => week.saturating_add(GALILEO_EPOCH_WEEK),
+            WeekWidth::Beidou13 => week.saturating_add(BEIDOU_EPOCH_WEEK),
+        }
+    }
+}
+
+/// Expand a truncated week number to the full week closest to `reference_week`.
+///
+/// Both weeks must be counted from the same epoch, so Galileo and BeiDou weeks need a reference week in their own
+/// time scale, as from [`WeekWidth::week_of`]; [`WeekWidth::gps_week`] converts the result back. The result is
+/// unambiguous while the reference is within half a rollover period of the actual week.
+pub fn resolve_truncated_week(
+    truncated: u16,
+    width: WeekWidth,
+    reference_week: i64,
+) -> Result<i64, GpstError> {
+    let modulus = width.modulus();
+    if truncated as i64 >= modulus {
+        return Err(GpstError::TruncatedWeek(truncated));
+    }
+    let cycle_start = reference_week - reference_week.rem_euclid(modulus);
+    let week = cycle_start + truncated as i64;
+    Ok(if week - reference_week > modulus / 2 {
+        week - modulus
+    } else if reference_week - week > modulus / 2 {
+        week + modulus
+    } else {
+        week
+    })
+}
+
+impl Gpst {
+    /// Week number modulo 1024, as broadcast in the legacy navigation message.
+    pub fn truncated_week(&self) -> u16 {
+        self.truncated_week_as(WeekWidth::Gps10)
+    }
+
+    /// Week number on the given width's week count (see [`WeekWidth::week_of`]), truncated to its bit width.
+    pub fn truncated_week_as(&self, width: WeekWidth) -> u16 {
+        width.week_of(self).rem_euclid(width.modulus()) as u16
+    }
+
+    /// The GPS week rollover cycle (0 until the August 1999 rollover, 1 until April 2019, 2 since) and the 10-bit
+    /// week within it, as `(cycle, week10)`.
+    pub fn cycle_and_week10(&self) -> (i64, u16) {
+        (
+            self.week.div_euclid(WeekWidth::Gps10.modulus()),
+            self.truncated_week(),
+        )
+    }
+
+    /// Build GPST data from a rollover cycle, 10-bit week and week seconds, the inverse of
+    /// [`Gpst::cycle_and_week10`]. Unlike [`from_truncated_week`], no reference date is needed.
+    ///
+    /// Fails on 10-bit weeks past 1023, negative cycles and week seconds outside of `0..604800`.
+    pub fn from_cycle_week10(cycle: i64, week10: u16, tow: f64) -> Result<Gpst, GpstError> {
+        let modulus = WeekWidth::Gps10.modulus();
+        if week10 as i64 >= modulus {
+            return Err(GpstError::TruncatedWeek(week10));
+        }
+        let week = cycle
+            .checked_mul(modulus)
+            .and_then(|week| week.checked_add(week10 as i64))
+            .ok_or(GpstError::Overflow(TimeInput::Week(cycle)))?;
+        Gpst::new(week, tow)
+    }
+}
+
+/// Given a 10-bit week number and week seconds, resolve the GPS week rollover ambiguity relative to a reference date.
+/// The full week closest to the reference is chosen, so the reference must be within ~9.8 years of the actual time.
+/// Fails like [`Gpst::new`] on week seconds outside of the week.
+pub fn from_truncated_week(
+    week10: u16,
+    tow: f64,
+    reference: DateTime<Utc>,
+) -> Result<Gpst, GpstError> {
+    let reference_week = reference.gpst(true)?.week;
+    let week = resolve_truncated_week(week10, WeekWidth::Gps10, reference_week)?;
+    Gpst::new(week, tow)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_truncated_week, resolve_truncated_week, Gpst, GpstError, WeekWidth};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn truncated_week() {
+        assert_eq!(Gpst::normalized(2280, 0.0).truncated_week(), 232);
+        assert_eq!(Gpst::normalized(1023, 0.0).truncated_week(), 1023);
+    }
+
+    #[test]
+    fn cycle() {
+        let gpst = Gpst::normalized(2280, 100.0);
+        assert_eq!(gpst.cycle_and_week10(), (2, 232));
+        assert_eq!(Gpst::from_cycle_week10(2, 232, 100.0).unwrap(), gpst);
+        // Week 1023 of cycle 0, just before the August 1999 rollover.
+        assert_eq!(Gpst::normalized(1023, 0.0).cycle_and_week10(), (0, 1023));
+        assert_eq!(Gpst::normalized(1024, 0.0).cycle_and_week10(), (1, 0));
+        assert_eq!(
+            Gpst::from_cycle_week10(1, 1024, 0.0),
+            Err(GpstError::TruncatedWeek(1024))
+        );
+        assert!(Gpst::from_cycle_week10(-1, 0, 0.0).is_err());
+        assert!(Gpst::from_cycle_week10(i64::MAX, 0, 0.0).is_err());
+        assert!(Gpst::from_cycle_week10(1, 0, 604800.0).is_err());
+    }
+
+    #[test]
+    fn resolve() {
+        let reference = Utc.with_ymd_and_hms(2023, 9, 10, 0, 0, 0).unwrap();
+        assert_eq!(from_truncated_week(232, 0.0, reference).unwrap().week, 2280);
+        // Just before the 2019 rollover, seen from just after it.
+        let reference = Utc.with_ymd_and_hms(2019, 4, 10, 0, 0, 0).unwrap();
+        assert_eq!(
+            from_truncated_week(1023, 0.0, reference).unwrap().week,
+            2047
+        );
+        assert_eq!(from_truncated_week(0, 0.0, reference).unwrap().week, 2048);
+        assert!(from_truncated_week(1024, 0.0, reference).is_err());
+        assert_eq!(
+            from_truncated_week(0, 604800.0, reference),
+            Err(GpstError::TowOutOfRange {
+                tow: 604800.0,
+                valid: 0.0..604800.0
+            })
+        );
+        assert_eq!(
+            from_truncated_week(0, -1.0, reference),
+            Err(GpstError::TowOutOfRange {
+                tow: -1.0,
+                valid: 0.0..604800.0
+            })
+        );
+        assert!(matches!(
+            from_truncated_week(0, f64::NAN, reference),
+            Err(GpstError::NonFiniteInput(_))
+        ));
+    }
+
+    #[test]
+    fn widths() {
+        assert_eq!(
+            Gpst::normalized(2280, 0.0).truncated_week_as(WeekWidth::Almanac8),
+            232
+        );
+        assert_eq!(
+            resolve_truncated_week(232, WeekWidth::Almanac8, 2300).unwrap(),
+            2280
+        );
+        assert_eq!(
+            resolve_truncated_week(1256, WeekWidth::Galileo12, 1250).unwrap(),
+            1256
+        );
+        assert_eq!(
+            resolve_truncated_week(8191, WeekWidth::Beidou13, 8193).unwrap(),
+            8191
+        );
+        assert!(resolve_truncated_week(256, WeekWidth::Almanac8, 2300).is_err());
+    }
+
+    #[test]
+    fn system_week_counts() {
+        assert_eq!(
+            WeekWidth::Galileo12.week_of(&Gpst::normalized(1024, 0.0)),
+            0
+        );
+        assert_eq!(
+            WeekWidth::Beidou13.week_of(&Gpst::normalized(1356, 14.0)),
+            0
+        );
+        assert_eq!(
+            WeekWidth::Beidou13.week_of(&Gpst::normalized(1356, 13.5)),
+            -1
+        );
+        assert_eq!(WeekWidth::Gps10.week_of(&Gpst::normalized(2280, 0.0)), 2280);
+
+        // A Galileo week broadcast in GPS week 2280 resolves against the GST reference, then converts back.
+        let gpst = Gpst::normalized(2280, 100.0);
+        let truncated = gpst.truncated_week_as(WeekWidth::Galileo12);
+        assert_eq!(truncated, 1256);
+        let reference = WeekWidth::Galileo12.week_of(&gpst);
+        let week = resolve_truncated_week(truncated, WeekWidth::Galileo12, reference).unwrap();
+        assert_eq!(WeekWidth::Galileo12.gps_week(week), 2280);
+
+        let truncated = gpst.truncated_week_as(WeekWidth::Beidou13);
+        assert_eq!(truncated, 924);
+        let reference = WeekWidth::Beidou13.week_of(&gpst);
+        let week = resolve_truncated_week(truncated, WeekWidth::Beidou13, reference).unwrap();
+        assert_eq!(WeekWidth::Beidou13.gps_week(week), 2280);
+        assert_eq!(WeekWidth::Beidou13.gps_week(i64::MAX), i64::MAX);
+    }
+}
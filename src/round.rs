@@ -0,0 +1,153 @@
+//! Rounding of fractional seconds, so formatted output and equality checks do not depend on float noise.
+
+use crate::{
+    from_gpst, from_wide_gps_nanoseconds, Gpst, GpstError, LeapHandling, TimeInput, TO_NANO_FLOAT,
+    TO_NANO_INT,
+};
+use chrono::{DateTime, Utc};
+
+/// How conversions round fractional seconds, see [`Gpst::rounded`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Round {
+    /// Round to the nearest whole second, with midpoints rounding up.
+    Nearest,
+    /// Round down to a whole second. Float noise below a nanosecond is removed first, so `480612.9999999999`
+    /// still floors to `480613`.
+    Floor,
+    /// Round to the nearest nanosecond, removing float noise such as `480612.999999999` but keeping fractional
+    /// seconds. The default.
+    #[default]
+    ToNanosecond,
+}
+
+impl Round {
+    /// Round nanoseconds since GPS Epoch.
+    pub(crate) fn apply(self, nanoseconds: i128) -> i128 {
+        let second = TO_NANO_INT as i128;
+        match self {
+            Round::Nearest => (nanoseconds + second / 2).div_euclid(second) * second,
+            Round::Floor => nanoseconds.div_euclid(second) * second,
+            Round::ToNanosecond => nanoseconds,
+        }
+    }
+}
+
+impl Gpst {
+    /// Round the week seconds, carrying into the week number, e.g. `480612.9999999` to `480613` with
+    /// [`Round::Nearest`] or [`Round::ToNanosecond`].
+    pub fn rounded(self, round: Round) -> Gpst {
+        let nanoseconds = round.apply(self.wide_nanoseconds_since_epoch());
+        Gpst::from_wide_nanoseconds(nanoseconds).derived_from(&self)
+    }
+}
+
+/// [`from_gpst`], rounding the week seconds first. Unlike [`from_gpst`], which truncates to whole nanoseconds, this
+/// converts `480612.9999999999` to `13:30:00` rather than `13:29:59.999999999` even with [`Round::ToNanosecond`].
+pub fn from_gpst_rounded(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+    round: Round,
+) -> Result<DateTime<Utc>, GpstError> {
+    let leap_seconds = leap_seconds.into();
+    // Validate as `from_gpst` does.
+    from_gpst(week, week_seconds, leap_seconds)?;
+    let gpst = Gpst::normalized(week, week_seconds).rounded(round);
+    from_wide_gps_nanoseconds(gpst.wide_nanoseconds_since_epoch(), leap_seconds)
+}
+
+/// [`crate::from_gpst_seconds`], rounding the seconds first.
+pub fn from_gpst_seconds_rounded(
+    seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+    round: Round,
+) -> Result<DateTime<Utc>, GpstError> {
+    if !seconds.is_finite() {
+        return Err(GpstError::NonFiniteInput(seconds));
+    }
+    if seconds < 0.0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::GpsSeconds(seconds)));
+    }
+    let nanoseconds = (seconds * TO_NANO_FLOAT).round();
+    if nanoseconds >= i64::MAX as f64 {
+        return Err(GpstError::Overflow(TimeInput::GpsSeconds(seconds)));
+    }
+    from_wide_gps_nanoseconds(round.apply(nanoseconds as i128), leap_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, from_gpst_rounded, from_gpst_seconds_rounded, Gpst, GpstLike, Round};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn rounded() {
+        let noisy = Gpst::normalized(1307, 480612.9999999999);
+        assert_eq!(
+            noisy.rounded(Round::ToNanosecond),
+            Gpst::normalized(1307, 480613.0)
+        );
+        assert_eq!(
+            noisy.rounded(Round::Nearest),
+            Gpst::normalized(1307, 480613.0)
+        );
+        assert_eq!(
+            noisy.rounded(Round::Floor),
+            Gpst::normalized(1307, 480613.0)
+        );
+        assert_eq!(
+            Gpst::normalized(1307, 480612.999).rounded(Round::Floor),
+            Gpst::normalized(1307, 480612.0)
+        );
+        let fraction = Gpst::normalized(1307, 604799.6);
+        assert_eq!(
+            fraction.rounded(Round::Nearest),
+            Gpst::normalized(1308, 0.0)
+        );
+        assert_eq!(
+            fraction.rounded(Round::Floor),
+            Gpst::normalized(1307, 604799.0)
+        );
+        assert_eq!(
+            fraction.rounded(Round::ToNanosecond),
+            Gpst::normalized(1307, 604799.6)
+        );
+
+        // Past `i64` nanoseconds since GPS Epoch.
+        let late = Gpst::normalized(16696, 604799.6).with_leap_handling(true);
+        assert_eq!(
+            late.rounded(Round::Nearest),
+            Gpst::normalized(16697, 0.0).with_leap_handling(true)
+        );
+        assert_eq!(
+            late.rounded(Round::Floor),
+            Gpst::normalized(16696, 604799.0).with_leap_handling(true)
+        );
+    }
+
+    #[test]
+    fn conversions() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        assert_ne!(from_gpst(1307, 480612.9999999999, true).unwrap(), date_time);
+        assert_eq!(
+            from_gpst_rounded(1307, 480612.9999999999, true, Round::ToNanosecond).unwrap(),
+            date_time
+        );
+        assert_eq!(
+            from_gpst_rounded(1307, 480613.4, true, Round::Floor).unwrap(),
+            date_time
+        );
+        assert!(from_gpst_rounded(1307, 604800.0, true, Round::Floor).is_err());
+        assert_eq!(
+            from_gpst_seconds_rounded(790954212.7, true, Round::Nearest).unwrap(),
+            date_time
+        );
+        assert!(from_gpst_seconds_rounded(f64::NAN, true, Round::Nearest).is_err());
+
+        let late = date_time + chrono::TimeDelta::milliseconds(700);
+        let gpst = late.gpst_rounded(true, Round::Nearest).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (1307, 480614.0));
+        let gpst = late.gpst_rounded(true, Round::Floor).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (1307, 480613.0));
+    }
+}
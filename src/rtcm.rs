@@ -0,0 +1,158 @@
+//! RTCM 3 MSM epoch time fields.
+//!
+//! GPS MSM messages carry the time of week in milliseconds (DF004, 30 bits). GLONASS MSM messages carry the day of
+//! week (DF416, 3 bits) and milliseconds of day (DF034, 27 bits) on GLONASS time, which is UTC + 3 hours. Neither
+//! carries a week number, so decoding resolves the epoch closest to a reference, handling week rollovers.
+
+use chrono::{DateTime, Datelike, TimeDelta, Timelike, Utc};
+
+use crate::{Gpst, GpstError, NANOSECONDS_PER_WEEK};
+
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+const MILLISECONDS_PER_WEEK: i64 = 7 * MILLISECONDS_PER_DAY;
+const NANOSECONDS_PER_MILLISECOND: i64 = 1_000_000;
+/// GLONASS time is UTC(SU), 3 hours ahead of UTC.
+const GLONASS_UTC_OFFSET: TimeDelta = TimeDelta::hours(3);
+/// DF416 value for an unknown day of week.
+const UNKNOWN_DAY_OF_WEEK: u8 = 7;
+
+/// Move `milliseconds` by whole periods to be closest to `reference`.
+fn closest(milliseconds: i64, reference: i64, period: i64) -> i64 {
+    let offset = (milliseconds - reference + period / 2).rem_euclid(period) - period / 2;
+    reference + offset
+}
+
+/// Encode the GPS epoch time (DF004): milliseconds of the GPS week.
+pub fn gps_epoch_time(gpst: &Gpst) -> u32 {
+    gpst.itow_ms()
+}
+
+/// Decode a GPS epoch time (DF004) to the epoch closest to `reference`, which must be within half a week.
+pub fn from_gps_epoch_time(epoch_time: u32, reference: &Gpst) -> Result<Gpst, GpstError> {
+    if epoch_time as i64 >= MILLISECONDS_PER_WEEK {
+        return Err(GpstError::RtcmEpoch(epoch_time));
+    }
+    // Resolve within the reference week, which the result may leave for the week before or after.
+    let reference_milliseconds = reference.week_nanoseconds() / NANOSECONDS_PER_MILLISECOND;
+    let milliseconds = closest(
+        epoch_time as i64,
+        reference_milliseconds,
+        MILLISECONDS_PER_WEEK,
+    );
+    Ok(Gpst::from_wide_nanoseconds(
+        reference.week as i128 * NANOSECONDS_PER_WEEK as i128
+            + (milliseconds * NANOSECONDS_PER_MILLISECOND) as i128,
+    ))
+}
+
+/// Encode the GLONASS day of week (DF416, 0 = Sunday) and epoch time (DF034, milliseconds of day) of a UTC instant.
+pub fn glonass_epoch_time(date_time: &DateTime<Utc>) -> (u8, u32) {
+    let glonass = date_time.naive_utc() + GLONASS_UTC_OFFSET;
+    let milliseconds = glonass.num_seconds_from_midnight() as u64 * 1000
+        + glonass.nanosecond() as u64 / NANOSECONDS_PER_MILLISECOND as u64;
+    (
+        glonass.weekday().num_days_from_sunday() as u8,
+        milliseconds as u32,
+    )
+}
+
+/// Decode a GLONASS day of week (DF416, 0 = Sunday, 7 = unknown) and epoch time (DF034) to the UTC instant closest
+/// to `reference`. The reference must be within half a week, or within half a day when the day is unknown.
+///
+/// DF034 reserves 86400000 to 86400999 for an epoch during a leap second, which has no unique UTC instant, so epoch
+/// times from one day of milliseconds on are rejected.
+pub fn from_glonass_epoch_time(
+    day_of_week: u8,
+    epoch_time: u32,
+    reference: DateTime<Utc>,
+) -> Result<DateTime<Utc>, GpstError> {
+    if day_of_week > UNKNOWN_DAY_OF_WEEK {
+        return Err(GpstError::DayOfWeek(day_of_week));
+    }
+    if epoch_time as i64 >= MILLISECONDS_PER_DAY {
+        return Err(GpstError::RtcmEpoch(epoch_time));
+    }
+    let glonass_reference = reference.naive_utc() + GLONASS_UTC_OFFSET;
+    let day_start = glonass_reference
+        .date()
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight exists");
+    let reference_milliseconds = (glonass_reference - day_start).num_milliseconds();
+    let glonass = if day_of_week == UNKNOWN_DAY_OF_WEEK {
+        let milliseconds = closest(
+            epoch_time as i64,
+            reference_milliseconds,
+            MILLISECONDS_PER_DAY,
+        );
+        day_start + TimeDelta::milliseconds(milliseconds)
+    } else {
+        let reference_day = glonass_reference.weekday().num_days_from_sunday() as i64;
+        let week_start = day_start - TimeDelta::days(reference_day);
+        let milliseconds = day_of_week as i64 * MILLISECONDS_PER_DAY + epoch_time as i64;
+        let reference_milliseconds = reference_day * MILLISECONDS_PER_DAY + reference_milliseconds;
+        let milliseconds = closest(milliseconds, reference_milliseconds, MILLISECONDS_PER_WEEK);
+        week_start + TimeDelta::milliseconds(milliseconds)
+    };
+    Ok((glonass - GLONASS_UTC_OFFSET).and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{rtcm, Gpst, GpstError};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn gps() {
+        let gpst = Gpst::normalized(1307, 480613.25);
+        assert_eq!(rtcm::gps_epoch_time(&gpst), 480_613_250);
+        assert_eq!(rtcm::from_gps_epoch_time(480_613_250, &gpst).unwrap(), gpst);
+        // An epoch just after the week rollover, decoded against a reference just before it.
+        let reference = Gpst::normalized(1307, 604799.0);
+        assert_eq!(
+            rtcm::from_gps_epoch_time(1_000, &reference).unwrap(),
+            Gpst::normalized(1308, 1.0)
+        );
+        assert!(rtcm::from_gps_epoch_time(604_800_000, &reference).is_err());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let reference = Gpst::normalized(20000, 604799.0);
+        assert_eq!(
+            rtcm::from_gps_epoch_time(1_000, &reference).unwrap(),
+            Gpst::normalized(20001, 1.0)
+        );
+        assert_eq!(
+            rtcm::from_gps_epoch_time(604_798_500, &reference).unwrap(),
+            Gpst::normalized(20000, 604798.5)
+        );
+    }
+
+    #[test]
+    fn glonass() {
+        // Saturday 22:30 UTC is Sunday 01:30 GLONASS time.
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 29, 22, 30, 0).unwrap();
+        let (day_of_week, epoch_time) = rtcm::glonass_epoch_time(&date_time);
+        assert_eq!((day_of_week, epoch_time), (0, 5_400_000));
+        let reference = Utc.with_ymd_and_hms(2005, 1, 29, 21, 0, 0).unwrap();
+        assert_eq!(
+            rtcm::from_glonass_epoch_time(day_of_week, epoch_time, reference).unwrap(),
+            date_time
+        );
+        assert_eq!(
+            rtcm::from_glonass_epoch_time(7, epoch_time, reference).unwrap(),
+            date_time
+        );
+        assert_eq!(
+            rtcm::from_glonass_epoch_time(8, epoch_time, reference),
+            Err(GpstError::DayOfWeek(8))
+        );
+        assert!(rtcm::from_glonass_epoch_time(0, 86_399_999, reference).is_ok());
+        for epoch_time in [86_400_000, 86_400_999, 86_401_000] {
+            assert_eq!(
+                rtcm::from_glonass_epoch_time(0, epoch_time, reference),
+                Err(GpstError::RtcmEpoch(epoch_time))
+            );
+        }
+    }
+}
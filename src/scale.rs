@@ -0,0 +1,207 @@
+//! GNSS time scales, per-scale calendar conventions and broadcast inter-system offsets.
+
+use chrono::{Datelike, NaiveDateTime};
+
+use crate::{
+    from_wide_gps_nanoseconds, Gpst, GpstError, SECONDS_PER_WEEK, TO_NANO_FLOAT, TO_NANO_INT,
+};
+
+/// A time scale epochs can be expressed in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time.
+    Utc,
+    /// GPS Time.
+    Gps,
+    /// Galileo System Time, steered to GPS Time with no integer second offset.
+    Galileo,
+    /// BeiDou Time, 14 seconds behind GPS Time.
+    Beidou,
+}
+
+impl TimeScale {
+    /// Whole seconds this scale runs behind GPS Time, for the scales with a fixed offset.
+    pub(crate) fn seconds_behind_gps(&self) -> Option<i64> {
+        match self {
+            TimeScale::Utc => None,
+            TimeScale::Gps | TimeScale::Galileo => Some(0),
+            TimeScale::Beidou => Some(14),
+        }
+    }
+}
+
+/// A broadcast inter-system time offset polynomial, e.g. the Galileo GGTO (A0G, A1G, t0G, WN0G) or the BeiDou BGTO
+/// (A0GPS, A1GPS), giving the other scale minus GPS Time beyond its whole second offset:
+/// `A0 + A1 (t − t_ref + 604800 (WN − week_ref))` seconds.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SystemTimeOffset {
+    /// Bias (s).
+    pub a0: f64,
+    /// Drift (s/s).
+    pub a1: f64,
+    /// Reference time of week (s).
+    pub t_ref: f64,
+    /// Reference week, on the GPS week count.
+    pub week_ref: i64,
+}
+
+impl SystemTimeOffset {
+    /// The offset in seconds at an epoch.
+    pub fn at(&self, epoch: &Gpst) -> f64 {
+        let elapsed = epoch.week_seconds - self.t_ref
+            + (epoch.week - self.week_ref) as f64 * SECONDS_PER_WEEK;
+        self.a0 + self.a1 * elapsed
+    }
+
+    fn nanoseconds_at(&self, epoch: &Gpst) -> i128 {
+        (self.at(epoch) * TO_NANO_FLOAT).round() as i128
+    }
+}
+
+/// Broadcast GPS to Galileo (GGTO) and GPS to BeiDou (BGTO) time offsets, zero by default.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct SystemTimeOffsets {
+    /// Galileo System Time minus GPS Time.
+    pub galileo: SystemTimeOffset,
+    /// BeiDou Time minus GPS Time, beyond the 14 second offset.
+    pub beidou: SystemTimeOffset,
+}
+
+impl SystemTimeOffsets {
+    /// Convert an epoch between the GPS, Galileo and BeiDou time scales, applying the whole second offset of each
+    /// scale and the broadcast offsets.
+    ///
+    /// Epochs on every scale are counted in weeks and seconds from GPS Epoch as read on that scale's clock; renumber
+    /// Galileo and BeiDou weeks separately. UTC is not a GNSS scale, use the leap second conversions for it.
+    pub fn convert(&self, epoch: &Gpst, from: TimeScale, to: TimeScale) -> Result<Gpst, GpstError> {
+        let gps = self.gps_of(epoch, from)?;
+        self.on_scale(&gps, to)
+    }
+
+    /// Whole seconds behind GPS Time and broadcast offset of a GNSS scale.
+    fn offset(&self, scale: TimeScale) -> Result<(i64, SystemTimeOffset), GpstError> {
+        let offset = match scale {
+            TimeScale::Utc => return Err(GpstError::UnsupportedTimeScale(scale)),
+            TimeScale::Gps => SystemTimeOffset::default(),
+            TimeScale::Galileo => self.galileo,
+            TimeScale::Beidou => self.beidou,
+        };
+        Ok((scale.seconds_behind_gps().unwrap_or_default(), offset))
+    }
+
+    fn gps_of(&self, epoch: &Gpst, scale: TimeScale) -> Result<Gpst, GpstError> {
+        let (seconds_behind, offset) = self.offset(scale)?;
+        let nanoseconds = epoch.wide_nanoseconds_since_epoch()
+            + (seconds_behind * TO_NANO_INT) as i128
+            - offset.nanoseconds_at(epoch);
+        Ok(Gpst::from_wide_nanoseconds(nanoseconds).derived_from(epoch))
+    }
+
+    fn on_scale(&self, gps: &Gpst, scale: TimeScale) -> Result<Gpst, GpstError> {
+        let (seconds_behind, offset) = self.offset(scale)?;
+        let nanoseconds = gps.wide_nanoseconds_since_epoch()
+            - (seconds_behind * TO_NANO_INT) as i128
+            + offset.nanoseconds_at(gps);
+        Ok(Gpst::from_wide_nanoseconds(nanoseconds).derived_from(gps))
+    }
+}
+
+impl Gpst {
+    /// The calendar date-time of this (leap second adjusted) GPS epoch on another time scale.
+    pub(crate) fn calendar_in(&self, scale: TimeScale) -> Result<NaiveDateTime, GpstError> {
+        let nanoseconds = self.wide_nanoseconds_since_epoch();
+        let (nanoseconds, leap_seconds) = match scale.seconds_behind_gps() {
+            Some(seconds) => (nanoseconds - (seconds * TO_NANO_INT) as i128, false),
+            None => (nanoseconds, true),
+        };
+        Ok(from_wide_gps_nanoseconds(nanoseconds, leap_seconds)?.naive_utc())
+    }
+
+    /// Year and day of year (1-based) of this (leap second adjusted) GPS epoch on a time scale's calendar.
+    ///
+    /// Scales are offset from each other by whole seconds, so close to midnight the day of year differs between
+    /// them, and product filenames must pick the convention of their constellation explicitly.
+    pub fn year_doy_in(&self, scale: TimeScale) -> Result<(i32, u32), GpstError> {
+        let date_time = self.calendar_in(scale)?;
+        Ok((date_time.year(), date_time.ordinal()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Gpst, GpstError, GpstLike, SystemTimeOffset, SystemTimeOffsets, TimeScale};
+    use chrono::{TimeZone, Utc};
+
+    #[test]
+    fn doy_near_midnight() {
+        // 2016-12-31 23:59:50 UTC is 2017-01-01 00:00:07 GPST and 2016-12-31 23:59:53 BDT.
+        let gpst = Utc
+            .with_ymd_and_hms(2016, 12, 31, 23, 59, 50)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        assert_eq!(gpst.year_doy_in(TimeScale::Utc).unwrap(), (2016, 366));
+        assert_eq!(gpst.year_doy_in(TimeScale::Gps).unwrap(), (2017, 1));
+        assert_eq!(gpst.year_doy_in(TimeScale::Galileo).unwrap(), (2017, 1));
+        assert_eq!(gpst.year_doy_in(TimeScale::Beidou).unwrap(), (2016, 366));
+    }
+
+    #[test]
+    fn system_offsets() {
+        let gps = Gpst::normalized(2280, 1000.0);
+        let zero = SystemTimeOffsets::default();
+        assert_eq!(
+            zero.convert(&gps, TimeScale::Gps, TimeScale::Galileo)
+                .unwrap(),
+            gps
+        );
+        assert_eq!(
+            zero.convert(&gps, TimeScale::Gps, TimeScale::Beidou)
+                .unwrap(),
+            Gpst::normalized(2280, 986.0)
+        );
+
+        let offsets = SystemTimeOffsets {
+            galileo: SystemTimeOffset {
+                a0: 2e-9,
+                a1: 1e-14,
+                t_ref: 900.0,
+                week_ref: 2280,
+            },
+            beidou: SystemTimeOffset {
+                a0: -5e-9,
+                ..SystemTimeOffset::default()
+            },
+        };
+        assert!((offsets.galileo.at(&gps) - 1e-12 - 2e-9).abs() < 1e-18);
+        let galileo = offsets
+            .convert(&gps, TimeScale::Gps, TimeScale::Galileo)
+            .unwrap();
+        assert_eq!(galileo, Gpst::normalized(2280, 1000.000000002));
+        assert_eq!(
+            offsets
+                .convert(&galileo, TimeScale::Galileo, TimeScale::Gps)
+                .unwrap(),
+            gps
+        );
+        let beidou = offsets
+            .convert(&galileo, TimeScale::Galileo, TimeScale::Beidou)
+            .unwrap();
+        assert_eq!(beidou, Gpst::normalized(2280, 985.999999995));
+        assert_eq!(
+            offsets.convert(&gps, TimeScale::Gps, TimeScale::Utc),
+            Err(GpstError::UnsupportedTimeScale(TimeScale::Utc))
+        );
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let gps = Gpst::normalized(16696, 10.0);
+        let beidou = SystemTimeOffsets::default()
+            .convert(&gps, TimeScale::Gps, TimeScale::Beidou)
+            .unwrap();
+        assert_eq!(beidou, Gpst::normalized(16695, 604796.0));
+        assert_eq!(gps.year_doy_in(TimeScale::Gps).unwrap(), (2299, 365));
+        assert_eq!(gps.year_doy_in(TimeScale::Beidou).unwrap(), (2299, 364));
+    }
+}
@@ -0,0 +1,138 @@
+//! SP3 precise orbit file time records.
+//!
+//! SP3 epochs are calendar dates on the file's time system, which is GPS time for the vast majority of products, so
+//! they convert to [`Gpst`] without leap second adjustment.
+
+use chrono::{Datelike, NaiveDate, Timelike};
+
+use crate::{Gpst, GpstError, ParseInput};
+
+const NANOSECONDS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Parse a decimal seconds field exactly, to nanoseconds.
+fn parse_seconds(field: &str) -> Option<(u32, u32)> {
+    let (whole, fraction) = field.split_once('.').unwrap_or((field, ""));
+    if fraction.len() > 9 || !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let nanoseconds = format!("{fraction:0<9}").parse().ok()?;
+    Some((whole.parse().ok()?, nanoseconds))
+}
+
+/// Parse an SP3 epoch record, e.g. `*  2005  1 28 13 30 13.00000000`.
+pub fn parse_epoch(line: &str) -> Result<Gpst, GpstError> {
+    let error = || GpstError::Parse(ParseInput::new("SP3 epoch"));
+    let mut fields = line.strip_prefix('*').ok_or_else(error)?.split_whitespace();
+    let mut next = || fields.next().ok_or_else(error);
+    let year = next()?.parse().map_err(|_| error())?;
+    let month = next()?.parse().map_err(|_| error())?;
+    let day = next()?.parse().map_err(|_| error())?;
+    let hour = next()?.parse().map_err(|_| error())?;
+    let minute = next()?.parse().map_err(|_| error())?;
+    let (second, nanosecond) = parse_seconds(next()?).ok_or_else(error)?;
+    let date_time = NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|date| date.and_hms_nano_opt(hour, minute, second, nanosecond))
+        .ok_or_else(error)?;
+    Gpst::from_gps_calendar(date_time)
+}
+
+/// Format an SP3 epoch record, e.g. `*  2005  1 28 13 30 13.00000000`. `None` if outside chrono's date range.
+pub fn format_epoch(gpst: &Gpst) -> Option<String> {
+    // Round to the 8 decimals of the record first, so rounding carries into the calendar fields.
+    let nanoseconds = (gpst.wide_nanoseconds_since_epoch() + 5).div_euclid(10) * 10;
+    let date_time = Gpst::from_wide_nanoseconds(nanoseconds).gps_calendar()?;
+    Some(format!(
+        "*  {:4} {:2} {:2} {:2} {:2} {:2}.{:08}",
+        date_time.year(),
+        date_time.month(),
+        date_time.day(),
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        date_time.nanosecond() / 10
+    ))
+}
+
+/// Parse the GPS week and seconds of week of the first epoch from the second SP3 header line, e.g.
+/// `## 1307 480613.00000000   900.00000000 53398 0.5000000000000`.
+pub fn parse_header_week(line: &str) -> Result<Gpst, GpstError> {
+    let error = || GpstError::Parse(ParseInput::new("SP3 header"));
+    let mut fields = line
+        .strip_prefix("##")
+        .ok_or_else(error)?
+        .split_whitespace();
+    let week: i64 = fields
+        .next()
+        .ok_or_else(error)?
+        .parse()
+        .map_err(|_| error())?;
+    let (seconds, nanoseconds) =
+        parse_seconds(fields.next().ok_or_else(error)?).ok_or_else(error)?;
+    if week < 0 || seconds >= 604800 {
+        return Err(error());
+    }
+    let seconds = week
+        .checked_mul(604800)
+        .and_then(|week_start| week_start.checked_add(seconds as i64))
+        .ok_or_else(error)?;
+    Ok(Gpst::from_wide_nanoseconds(
+        seconds as i128 * NANOSECONDS_PER_SECOND as i128 + nanoseconds as i128,
+    ))
+}
+
+/// Format the GPS week and seconds of week fields of the second SP3 header line, e.g. `## 1307 480613.00000000`.
+pub fn format_header_week(gpst: &Gpst) -> String {
+    format!("## {:4} {:15.8}", gpst.week, gpst.week_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{sp3, Gpst};
+
+    #[test]
+    fn epoch() {
+        let gpst = sp3::parse_epoch("*  2005  1 28 13 30 13.00000000").unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.0));
+        assert_eq!(
+            sp3::format_epoch(&gpst).unwrap(),
+            "*  2005  1 28 13 30 13.00000000"
+        );
+        let gpst = sp3::parse_epoch("*  2023  9 10  0  0  0.12345678").unwrap();
+        assert_eq!(gpst.week, 2279);
+        assert_eq!(gpst.week_seconds, 0.12345678);
+        assert_eq!(
+            sp3::format_epoch(&gpst).unwrap(),
+            "*  2023  9 10  0  0  0.12345678"
+        );
+        assert!(sp3::parse_epoch("*  2005  1 28 13 30").is_err());
+        assert!(sp3::parse_epoch("   2005  1 28 13 30 13.0").is_err());
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let gpst = sp3::parse_epoch("*  2300  1  1 12  0 59.99999999").unwrap();
+        assert_eq!(gpst, Gpst::normalized(16696, 129659.99999999));
+        assert_eq!(
+            sp3::format_epoch(&gpst).unwrap(),
+            "*  2300  1  1 12  0 59.99999999"
+        );
+        assert_eq!(
+            sp3::format_epoch(&Gpst::normalized(16696, 129659.999999996)).unwrap(),
+            "*  2300  1  1 12  1  0.00000000"
+        );
+    }
+
+    #[test]
+    fn header() {
+        let line = "## 1307 480613.00000000   900.00000000 53398 0.5000000000000";
+        let gpst = sp3::parse_header_week(line).unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.0));
+        assert_eq!(sp3::format_header_week(&gpst), "## 1307 480613.00000000");
+        assert!(sp3::parse_header_week("## 1307 604800.0").is_err());
+        assert!(sp3::parse_header_week("## 99999999999999 0.0").is_err());
+        assert_eq!(
+            sp3::parse_header_week("## 16696 45.50000000").unwrap(),
+            Gpst::normalized(16696, 45.5)
+        );
+    }
+}
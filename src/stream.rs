@@ -0,0 +1,170 @@
+//! Lazy conversion adaptors for iterators, e.g. `epochs.into_iter().map_to_gpst(true)`.
+
+use core::borrow::Borrow;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    from_gps_nanoseconds_with, gps_nanoseconds_with, validate_week, Gpst, GpstError, LeapCursor,
+    LeapHandling, NANOSECONDS_PER_WEEK, TO_NANO_FLOAT,
+};
+
+/// Streaming conversions for any iterator, yielding `Result` items lazily.
+///
+/// Like the batch conversions, the adaptors cache the leap second lookup between consecutive items.
+pub trait GpstIteratorExt: Iterator + Sized {
+    /// Convert UTC instants to GPS Time (GPST). Optionally, adjust for leap seconds.
+    fn map_to_gpst(self, leap_seconds: impl Into<LeapHandling>) -> ToGpst<Self>
+    where
+        Self::Item: Borrow<DateTime<Utc>>,
+    {
+        ToGpst {
+            iter: self,
+            leap_seconds: leap_seconds.into(),
+            cursor: LeapCursor::utc(),
+        }
+    }
+
+    /// Convert `(week, week seconds)` pairs to UTC. Optionally, adjust for leap seconds.
+    ///
+    /// Each pair is validated as [`from_gpst`](crate::from_gpst) does, so bad pairs yield an `Err` item.
+    fn map_from_gpst(self, leap_seconds: impl Into<LeapHandling>) -> FromGpst<Self>
+    where
+        Self::Item: Borrow<(i64, f64)>,
+    {
+        FromGpst {
+            iter: self,
+            leap_seconds: leap_seconds.into(),
+            cursor: LeapCursor::gps(),
+        }
+    }
+}
+
+impl<I: Iterator> GpstIteratorExt for I {}
+
+/// Iterator adaptor returned by [`GpstIteratorExt::map_to_gpst`].
+pub struct ToGpst<I> {
+    iter: I,
+    leap_seconds: LeapHandling,
+    cursor: LeapCursor,
+}
+
+impl<I> Iterator for ToGpst<I>
+where
+    I: Iterator,
+    I::Item: Borrow<DateTime<Utc>>,
+{
+    type Item = Result<Gpst, GpstError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date_time = self.iter.next()?;
+        let cursor = &mut self.cursor;
+        Some(
+            gps_nanoseconds_with(date_time.borrow(), self.leap_seconds, |nanoseconds| {
+                cursor.leap_nanoseconds(nanoseconds)
+            })
+            .map(|nanoseconds| Gpst::from_converted_nanoseconds(nanoseconds, self.leap_seconds)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator adaptor returned by [`GpstIteratorExt::map_from_gpst`].
+pub struct FromGpst<I> {
+    iter: I,
+    leap_seconds: LeapHandling,
+    cursor: LeapCursor,
+}
+
+impl<I> Iterator for FromGpst<I>
+where
+    I: Iterator,
+    I::Item: Borrow<(i64, f64)>,
+{
+    type Item = Result<DateTime<Utc>, GpstError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(week, week_seconds) = self.iter.next()?.borrow();
+        if let Err(error) = validate_week(week, week_seconds) {
+            return Some(Err(error));
+        }
+        let cursor = &mut self.cursor;
+        let nanoseconds =
+            week as i128 * NANOSECONDS_PER_WEEK as i128 + (week_seconds * TO_NANO_FLOAT) as i128;
+        Some(from_gps_nanoseconds_with(
+            nanoseconds,
+            self.leap_seconds,
+            |nanoseconds| cursor.leap_nanoseconds(nanoseconds),
+        ))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, Gpst, GpstError, GpstIteratorExt, GpstLike};
+    use chrono::{TimeDelta, TimeZone, Utc};
+
+    #[test]
+    fn to_gpst() {
+        let start = Utc.with_ymd_and_hms(2016, 12, 31, 23, 59, 58).unwrap();
+        let gpst: Vec<Gpst> = (0..4)
+            .map(|step| start + TimeDelta::seconds(step))
+            .map_to_gpst(true)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let week_seconds: Vec<f64> = gpst.iter().map(|gpst| gpst.week_seconds).collect();
+        assert_eq!(week_seconds, [15.0, 16.0, 18.0, 19.0]);
+        assert_eq!(gpst[0], start.gpst(true).unwrap());
+    }
+
+    #[test]
+    fn from_gpst_pairs() {
+        let pairs = [(1307, 480613.0), (i64::MAX, 0.0)];
+        let mut date_times = pairs.iter().map_from_gpst(true);
+        assert_eq!(date_times.size_hint(), (2, Some(2)));
+        assert_eq!(
+            date_times.next().unwrap().unwrap(),
+            from_gpst(1307, 480613.0, true).unwrap()
+        );
+        assert!(date_times.next().unwrap().is_err());
+        assert!(date_times.next().is_none());
+    }
+
+    #[test]
+    fn invalid_pairs() {
+        let pairs = [
+            (1307, f64::NAN),
+            (1307, 604800.0),
+            (-1, 480613.0),
+            (1307, 480613.0),
+        ];
+        let date_times: Vec<_> = pairs.iter().map_from_gpst(true).collect();
+        assert!(matches!(date_times[0], Err(GpstError::NonFiniteInput(_))));
+        assert!(matches!(
+            date_times[1],
+            Err(GpstError::TowOutOfRange { .. })
+        ));
+        assert!(matches!(date_times[2], Err(GpstError::BeforeGPSEpoch(_))));
+        assert_eq!(date_times[3], from_gpst(1307, 480613.0, true));
+    }
+
+    #[test]
+    fn lazy() {
+        // Only the items taken are converted, so an unbounded source is fine.
+        let start = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let first = (0..)
+            .map(|step| start + TimeDelta::seconds(step))
+            .map_to_gpst(false)
+            .nth(2)
+            .unwrap()
+            .unwrap();
+        assert_eq!(first, (start + TimeDelta::seconds(2)).gpst(false).unwrap());
+    }
+}
@@ -0,0 +1,131 @@
+//! Week-at-a-glance summaries for processing reports.
+
+use core::fmt;
+
+use chrono::{DateTime, Datelike, TimeDelta, Utc};
+
+use crate::{
+    from_gpst_exact, from_wide_gps_nanoseconds, GpsWeek, Gpst, GpstError, WeekWidth, LEAP_SECONDS,
+    NANOSECONDS_PER_WEEK, TO_NANO_INT,
+};
+
+/// A structured summary of one GPS week, in UTC.
+///
+/// The [`fmt::Display`] implementation renders the default text form, e.g.:
+///
+/// ```text
+/// GPS week 1930 (10-bit week 906, 118 weeks to rollover)
+/// UTC 2016-12-31T23:59:43Z .. 2017-01-07T23:59:42Z
+/// DOY 2016/366 .. 2017/007
+/// Leap 2016-12-31T23:59:60Z
+/// ```
+///
+/// Reports that need another layout can render the fields themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeekSummary {
+    /// The summarized week.
+    pub week: GpsWeek,
+    /// UTC at the start of the week.
+    pub utc_start: DateTime<Utc>,
+    /// UTC at the start of the following week, i.e. the exclusive end of this week.
+    pub utc_end: DateTime<Utc>,
+    /// UTC year and day of year at the start of the week.
+    pub first_doy: (i32, u32),
+    /// UTC year and day of year at the last instant of the week.
+    pub last_doy: (i32, u32),
+    /// Leap seconds inserted during the week, as the UTC reading `23:59:60` in chrono's leap second representation.
+    pub leap_events: Vec<DateTime<Utc>>,
+    /// Broadcast 10-bit week number.
+    pub week10: u16,
+    /// Weeks left until the 10-bit week number rolls over to 0.
+    pub weeks_to_rollover: i64,
+}
+
+impl WeekSummary {
+    /// Summarize a GPS week.
+    pub fn new(week: GpsWeek) -> Result<WeekSummary, GpstError> {
+        let start = week.0 as i128 * NANOSECONDS_PER_WEEK as i128;
+        let end = start + NANOSECONDS_PER_WEEK as i128;
+        let utc_start = from_wide_gps_nanoseconds(start, true)?;
+        let utc_end = from_wide_gps_nanoseconds(end, true)?;
+        let leap_events = LEAP_SECONDS
+            .iter()
+            .map(|leap_second| leap_second * TO_NANO_INT)
+            .filter(|leap| (start..end).contains(&(*leap as i128)))
+            .map(|leap| {
+                let leap = Gpst::from_gps_nanoseconds(leap);
+                from_gpst_exact(leap.week, leap.week_seconds).map(|instant| instant.date_time())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let modulus = WeekWidth::Gps10.modulus();
+        Ok(WeekSummary {
+            week,
+            utc_start,
+            utc_end,
+            first_doy: year_doy(&utc_start),
+            last_doy: year_doy(&(utc_end - TimeDelta::nanoseconds(1))),
+            leap_events,
+            week10: week.0.rem_euclid(modulus) as u16,
+            weeks_to_rollover: modulus - week.0.rem_euclid(modulus),
+        })
+    }
+}
+
+fn year_doy(date_time: &DateTime<Utc>) -> (i32, u32) {
+    (date_time.year(), date_time.ordinal())
+}
+
+impl fmt::Display for WeekSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rfc3339 = |date_time: &DateTime<Utc>| date_time.format("%Y-%m-%dT%H:%M:%SZ");
+        writeln!(
+            f,
+            "GPS week {} (10-bit week {}, {} weeks to rollover)",
+            self.week.0, self.week10, self.weeks_to_rollover
+        )?;
+        writeln!(
+            f,
+            "UTC {} .. {}",
+            rfc3339(&self.utc_start),
+            rfc3339(&self.utc_end)
+        )?;
+        write!(
+            f,
+            "DOY {}/{:03} .. {}/{:03}",
+            self.first_doy.0, self.first_doy.1, self.last_doy.0, self.last_doy.1
+        )?;
+        for leap in &self.leap_events {
+            write!(f, "\nLeap {}", rfc3339(leap))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GpsWeek, WeekSummary};
+
+    #[test]
+    fn leap_week() {
+        let summary = WeekSummary::new(GpsWeek(1930)).unwrap();
+        assert_eq!(summary.first_doy, (2016, 366));
+        assert_eq!(summary.last_doy, (2017, 7));
+        assert_eq!(summary.leap_events.len(), 1);
+        assert_eq!(summary.weeks_to_rollover, 118);
+        assert_eq!(
+            summary.to_string(),
+            "GPS week 1930 (10-bit week 906, 118 weeks to rollover)\n\
+             UTC 2016-12-31T23:59:43Z .. 2017-01-07T23:59:42Z\n\
+             DOY 2016/366 .. 2017/007\n\
+             Leap 2016-12-31T23:59:60Z"
+        );
+    }
+
+    #[test]
+    fn quiet_week() {
+        let summary = WeekSummary::new(GpsWeek(2047)).unwrap();
+        assert!(summary.leap_events.is_empty());
+        assert_eq!((summary.week10, summary.weeks_to_rollover), (1023, 1));
+        assert!(!summary.to_string().contains("Leap"));
+    }
+}
@@ -0,0 +1,90 @@
+//! Interop with the [time](https://docs.rs/time/latest/time/) crate, enabled with the `time` feature.
+//!
+//! All conversions are routed through the chrono implementation, so both crates share one GPST algorithm.
+
+use chrono::{DateTime, Utc};
+use time::OffsetDateTime;
+
+use crate::{
+    from_gpst, from_gpst_seconds, Gpst, GpstError, GpstLike, LeapHandling, TimeInput, TO_NANO_INT,
+};
+
+impl GpstLike for OffsetDateTime {
+    fn gpst(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        to_chrono(self)?.gpst(leap_seconds)
+    }
+
+    fn gpst_signed(&self, leap_seconds: impl Into<LeapHandling>) -> Result<Gpst, GpstError> {
+        to_chrono(self)?.gpst_signed(leap_seconds)
+    }
+}
+
+/// Given seconds since GPS Epoch, convert to an [`OffsetDateTime`] in UTC. Optionally, adjust for leap seconds.
+pub fn offset_date_time_from_gpst_seconds(
+    seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<OffsetDateTime, GpstError> {
+    from_chrono(&from_gpst_seconds(seconds, leap_seconds)?)
+}
+
+/// Given weeks since GPS Epoch and week seconds, convert to an [`OffsetDateTime`] in UTC. Optionally, adjust for leap seconds.
+pub fn offset_date_time_from_gpst(
+    week: i64,
+    week_seconds: f64,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<OffsetDateTime, GpstError> {
+    from_chrono(&from_gpst(week, week_seconds, leap_seconds)?)
+}
+
+fn to_chrono(date_time: &OffsetDateTime) -> Result<DateTime<Utc>, GpstError> {
+    DateTime::from_timestamp(date_time.unix_timestamp(), date_time.nanosecond()).ok_or(
+        GpstError::TimestampNano(TimeInput::UnixNanoseconds(date_time.unix_timestamp_nanos())),
+    )
+}
+
+fn from_chrono(date_time: &DateTime<Utc>) -> Result<OffsetDateTime, GpstError> {
+    // Wide nanoseconds, as `timestamp_nanos_opt` stops at 2262 while `OffsetDateTime` reaches 9999.
+    let timestamp_nanos = date_time.timestamp() as i128 * TO_NANO_INT as i128
+        + date_time.timestamp_subsec_nanos() as i128;
+    OffsetDateTime::from_unix_timestamp_nanos(timestamp_nanos)
+        .map_err(|_| GpstError::TimestampNano(TimeInput::Utc(*date_time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{offset_date_time_from_gpst, Gpst, GpstLike};
+    use time::OffsetDateTime;
+
+    #[test]
+    fn to() {
+        let date_time = OffsetDateTime::from_unix_timestamp(1106919000).unwrap();
+        assert_eq!(
+            date_time.gpst(true).unwrap(),
+            Gpst {
+                seconds: 790954213.0,
+                week: 1307,
+                week_seconds: 480613.0,
+                leap_handling: Some(crate::LeapHandling::Step),
+            }
+        );
+    }
+
+    #[test]
+    fn from() {
+        let date_time = OffsetDateTime::from_unix_timestamp(1106919000).unwrap();
+        assert_eq!(
+            offset_date_time_from_gpst(1307, 480613.0, true).unwrap(),
+            date_time
+        );
+    }
+
+    #[test]
+    fn beyond_2262() {
+        // Week 20000 is in 2363, past i64 Unix nanoseconds.
+        let date_time = offset_date_time_from_gpst(20000, 0.5, true).unwrap();
+        assert_eq!(date_time.year(), 2363);
+        assert_eq!(date_time.nanosecond(), 500_000_000);
+        let gpst = date_time.gpst(true).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (20000, 0.5));
+    }
+}
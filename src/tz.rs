@@ -0,0 +1,196 @@
+//! A chrono [`TimeZone`] for GPS time, so GPST can be held as a `DateTime<GpsTime>`.
+
+use core::fmt;
+
+use chrono::{DateTime, FixedOffset, MappedLocalTime, NaiveDate, NaiveDateTime, Offset, TimeZone};
+
+use crate::{inserted_second, is_inserted_utc, num_leaps, num_leaps_utc, GPS_EPOCH, TO_NANO_INT};
+
+/// The GPS time scale as a chrono [`TimeZone`].
+///
+/// Wall time is UTC plus the leap seconds inserted since GPS Epoch, so `DateTime<GpsTime>` displays GPS time and
+/// converts to and from `DateTime<Utc>` via [`chrono::DateTime::with_timezone`].
+///
+/// The conversion is lossless across leap seconds: the GPS second during an insertion maps to UTC `23:59:60`, held in
+/// chrono's leap second representation (nanoseconds of `1_000_000_000` or more). chrono adds the whole second offset
+/// to that representation, so such a `DateTime<GpsTime>` keeps the offset from before the insertion and displays as
+/// `23:59:60`: the GPS wall time 1981-07-01 00:00:00.5 displays as 1981-06-30 23:59:60.5 GPST.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GpsTime;
+
+/// The offset of GPS time from UTC at a given instant, in whole leap seconds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GpsOffset {
+    leap_seconds: i32,
+}
+
+impl GpsOffset {
+    /// Leap seconds GPS time is ahead of UTC.
+    pub fn leap_seconds(&self) -> i32 {
+        self.leap_seconds
+    }
+}
+
+impl Offset for GpsOffset {
+    fn fix(&self) -> FixedOffset {
+        FixedOffset::east_opt(self.leap_seconds).expect("leap seconds are within a day")
+    }
+}
+
+impl fmt::Display for GpsOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GPST")
+    }
+}
+
+/// Nanoseconds since GPS Epoch for a naive date-time, saturating outside the representable range.
+fn nanoseconds_since_epoch(date_time: &NaiveDateTime) -> i64 {
+    let date_time = date_time.and_utc();
+    (date_time.timestamp() - GPS_EPOCH / TO_NANO_INT)
+        .saturating_mul(TO_NANO_INT)
+        .saturating_add(date_time.timestamp_subsec_nanos() as i64)
+}
+
+impl TimeZone for GpsTime {
+    type Offset = GpsOffset;
+
+    fn from_offset(_offset: &GpsOffset) -> Self {
+        GpsTime
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> MappedLocalTime<GpsOffset> {
+        self.offset_from_local_datetime(&local.and_time(chrono::NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> MappedLocalTime<GpsOffset> {
+        // GPS time has no discontinuities, so every wall time maps to exactly one instant. During an insertion, the
+        // offset from before it is kept, as by `offset_from_utc_datetime` for 23:59:60.
+        let nanoseconds = nanoseconds_since_epoch(local);
+        let nanoseconds = inserted_second(nanoseconds as i128).unwrap_or(nanoseconds);
+        let leap_seconds = num_leaps(nanoseconds) / TO_NANO_INT;
+        MappedLocalTime::Single(GpsOffset {
+            leap_seconds: leap_seconds as i32,
+        })
+    }
+
+    fn from_local_datetime(&self, local: &NaiveDateTime) -> MappedLocalTime<DateTime<GpsTime>> {
+        let MappedLocalTime::Single(offset) = self.offset_from_local_datetime(local) else {
+            return MappedLocalTime::None;
+        };
+        let nanoseconds = nanoseconds_since_epoch(local);
+        let utc = match inserted_second(nanoseconds as i128) {
+            // Read the inserted second as 23:59:60 UTC, i.e. 23:59:59 with leap nanoseconds, instead of subtracting
+            // the offset onto the second before or after it.
+            Some(start) => {
+                let leap_nanoseconds = offset.leap_seconds as i64 * TO_NANO_INT;
+                let before = (start - leap_nanoseconds - TO_NANO_INT + GPS_EPOCH) / TO_NANO_INT;
+                let nanosecond = (TO_NANO_INT + nanoseconds - start) as u32;
+                DateTime::from_timestamp(before, nanosecond).map(|date_time| date_time.naive_utc())
+            }
+            None => local.checked_sub_offset(offset.fix()),
+        };
+        match utc {
+            Some(utc) => MappedLocalTime::Single(DateTime::from_naive_utc_and_offset(utc, offset)),
+            None => MappedLocalTime::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> GpsOffset {
+        self.offset_from_utc_datetime(&utc.and_time(chrono::NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> GpsOffset {
+        let nanoseconds = nanoseconds_since_epoch(utc);
+        let mut leap_seconds = num_leaps_utc(nanoseconds) / TO_NANO_INT;
+        // chrono folds a 23:59:60 reading onto the following second, where the new leap second is already counted.
+        if is_inserted_utc(&utc.and_utc(), nanoseconds) {
+            leap_seconds -= 1;
+        }
+        GpsOffset {
+            leap_seconds: leap_seconds as i32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tz::nanoseconds_since_epoch;
+    use crate::GpsTime;
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    #[test]
+    fn display() {
+        let date_time = NaiveDate::from_ymd_opt(2005, 1, 28)
+            .unwrap()
+            .and_hms_opt(13, 30, 0)
+            .unwrap()
+            .and_utc();
+        let gps_time = date_time.with_timezone(&GpsTime);
+        assert_eq!(gps_time.offset().leap_seconds(), 13);
+        assert_eq!(gps_time.to_string(), "2005-01-28 13:30:13 GPST");
+        assert_eq!(gps_time.with_timezone(&Utc), date_time);
+    }
+
+    #[test]
+    fn from_local() {
+        let gps_time = GpsTime.with_ymd_and_hms(1981, 7, 1, 0, 0, 1).unwrap();
+        assert_eq!(
+            gps_time.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(1981, 7, 1, 0, 0, 0).unwrap()
+        );
+        let before_leap = GpsTime.with_ymd_and_hms(1981, 6, 30, 23, 59, 59).unwrap();
+        assert_eq!(
+            before_leap.with_timezone(&Utc),
+            Utc.with_ymd_and_hms(1981, 6, 30, 23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn leap_insertion() {
+        // GPS wall time 1981-07-01 00:00:00.5 is 1981-06-30 23:59:60.5 UTC, and 00:00:01.5 is 00:00:00.5 UTC.
+        let inserted = NaiveDate::from_ymd_opt(1981, 7, 1)
+            .unwrap()
+            .and_hms_milli_opt(0, 0, 0, 500)
+            .unwrap();
+        let reading = NaiveDate::from_ymd_opt(1981, 6, 30)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        let gps_time = GpsTime.from_local_datetime(&inserted).unwrap();
+        assert_eq!(gps_time.with_timezone(&Utc), reading);
+        assert_eq!(reading.with_timezone(&GpsTime), gps_time);
+        assert_eq!(gps_time.offset().leap_seconds(), 0);
+        assert_eq!(gps_time.to_string(), "1981-06-30 23:59:60.500 GPST");
+        // The displayed wall time converts back to the same instant.
+        assert_eq!(
+            GpsTime
+                .from_local_datetime(&gps_time.naive_local())
+                .unwrap(),
+            gps_time
+        );
+
+        let after = GpsTime.with_ymd_and_hms(1981, 7, 1, 0, 0, 1).unwrap();
+        let utc = Utc.with_ymd_and_hms(1981, 7, 1, 0, 0, 0).unwrap();
+        assert_eq!(after.with_timezone(&Utc), utc);
+        assert_eq!(utc.with_timezone(&GpsTime), after);
+        assert_eq!(
+            GpsTime
+                .with_ymd_and_hms(1981, 7, 1, 0, 0, 0)
+                .unwrap()
+                .with_timezone(&Utc),
+            reading - chrono::TimeDelta::milliseconds(500)
+        );
+    }
+
+    #[test]
+    fn subsec_nanoseconds() {
+        let date_time = NaiveDate::from_ymd_opt(1980, 1, 6)
+            .unwrap()
+            .and_hms_nano_opt(0, 0, 1, 250_000_000)
+            .unwrap();
+        assert_eq!(nanoseconds_since_epoch(&date_time), 1_250_000_000);
+        let gps_time = GpsTime.from_local_datetime(&date_time).unwrap();
+        assert_eq!(gps_time.with_timezone(&Utc).naive_utc(), date_time);
+    }
+}
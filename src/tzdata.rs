@@ -0,0 +1,521 @@
+//! Leap second tables loaded at runtime from the system tzdata, so hosts with maintained tzdata pick up leap seconds
+//! announced after this crate was released.
+//!
+//! Both tzdata formats are supported: the NTP-style `leap-seconds.list` and the zic-style `leapseconds`.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+use crate::{
+    count_leaps, from_gps_nanoseconds_with, gps_nanoseconds_with, Gpst, GpstError, LeapHandling,
+    ParseInput, TimeConverter, GPS_EPOCH, LEAP_SECONDS, LEAP_TABLE_EXPIRES, TO_NANO_INT,
+};
+
+/// NTP timestamp (seconds since 1900-01-01) of the Unix epoch.
+const NTP_UNIX_EPOCH: i64 = 2208988800;
+/// TAI - UTC at GPS Epoch.
+const TAI_UTC_GPS_EPOCH: i64 = 19;
+const SECONDS_PER_DAY: i64 = 86400;
+/// tzdata file names, in order of preference.
+const FILE_NAMES: [&str; 2] = ["leap-seconds.list", "leapseconds"];
+
+/// A leap second table, e.g. from the system tzdata, usable as a [`TimeConverter`] with stepped leap seconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeapTable {
+    /// Start of each inserted leap second since GPS Epoch, in seconds on the GPS time scale.
+    leap_seconds: Vec<i64>,
+    /// The same in nanoseconds.
+    leap_nanoseconds: Vec<i64>,
+    /// The same on the UTC time scale, shifted back by the leap seconds preceding each.
+    leap_nanoseconds_utc: Vec<i64>,
+    /// Change of TAI - UTC at each leap second, 1 unless the source declares otherwise.
+    steps: Vec<i64>,
+    expires: Option<DateTime<Utc>>,
+}
+
+/// A problem found by [`LeapTable::validate_leap_table`], with the index of the offending leap second.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LeapTableIssue {
+    /// A leap second is not after the previous one.
+    NotIncreasing {
+        /// Index of the leap second.
+        index: usize,
+    },
+    /// A leap second does not end a UTC day.
+    NotAtMidnight {
+        /// Index of the leap second.
+        index: usize,
+    },
+    /// TAI - UTC changes by other than one second, which conversions do not model.
+    Step {
+        /// Index of the leap second.
+        index: usize,
+        /// Change of TAI - UTC in seconds.
+        step: i64,
+    },
+    /// A leap second disagrees with the builtin historical table. Later entries are not compared.
+    Historical {
+        /// Index of the leap second.
+        index: usize,
+        /// The builtin leap second, in GPS seconds since GPS Epoch.
+        expected: i64,
+        /// The table's leap second, in GPS seconds since GPS Epoch.
+        found: i64,
+    },
+    /// The table ends before the builtin historical table does.
+    MissingHistorical {
+        /// Number of builtin leap seconds missing from the table.
+        missing: usize,
+    },
+    /// The table declares no expiry date, so it cannot be known complete for any date.
+    NoExpiry,
+    /// The table expires before its own last leap second.
+    ExpiresBeforeLastLeap {
+        /// The declared expiry date.
+        expires: DateTime<Utc>,
+    },
+}
+
+impl fmt::Display for LeapTableIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeapTableIssue::NotIncreasing { index } => {
+                write!(f, "leap second {index} is not after the previous one")
+            }
+            LeapTableIssue::NotAtMidnight { index } => {
+                write!(f, "leap second {index} does not end a UTC day")
+            }
+            LeapTableIssue::Step { index, step } => {
+                write!(f, "leap second {index} changes TAI - UTC by {step} s")
+            }
+            LeapTableIssue::Historical {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "leap second {index} at {found} GPS seconds, expected {expected}"
+            ),
+            LeapTableIssue::MissingHistorical { missing } => {
+                write!(f, "{missing} historical leap seconds missing")
+            }
+            LeapTableIssue::NoExpiry => write!(f, "no expiry date"),
+            LeapTableIssue::ExpiresBeforeLastLeap { expires } => {
+                write!(f, "expires on {expires}, before its last leap second")
+            }
+        }
+    }
+}
+
+impl LeapTable {
+    /// The table builtin to this crate.
+    pub fn builtin() -> LeapTable {
+        let expires = LEAP_TABLE_EXPIRES
+            .and_hms_opt(0, 0, 0)
+            .map(|date_time| date_time.and_utc());
+        LeapTable::new(LEAP_SECONDS.to_vec(), vec![1; LEAP_SECONDS.len()], expires)
+    }
+
+    /// Parse an NTP-style `leap-seconds.list`, as published by IERS and shipped in tzdata.
+    pub fn parse_leap_seconds_list(list: &str) -> Result<LeapTable, GpstError> {
+        let mut leap_seconds = Vec::new();
+        let mut steps = Vec::new();
+        let mut previous_tai_utc = TAI_UTC_GPS_EPOCH;
+        let mut expires = None;
+        for (number, line) in list.lines().enumerate() {
+            let invalid = || GpstError::Parse(ParseInput::at_line("leap-seconds.list", number + 1));
+            if let Some(expiry) = line.strip_prefix("#@") {
+                let ntp_seconds: i64 = expiry.trim().parse().map_err(|_| invalid())?;
+                expires = Some(from_ntp_seconds(ntp_seconds).ok_or_else(invalid)?);
+                continue;
+            }
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let mut fields = line.split_whitespace().map(str::parse::<i64>);
+            let (Some(Ok(ntp_seconds)), Some(Ok(tai_utc))) = (fields.next(), fields.next()) else {
+                return Err(invalid());
+            };
+            if tai_utc <= TAI_UTC_GPS_EPOCH {
+                continue;
+            }
+            let effective = from_ntp_seconds(ntp_seconds).ok_or_else(invalid)?;
+            // Each entry is the first UTC midnight with the new TAI - UTC.
+            let leap_second = inserted_second(effective.naive_utc(), leap_seconds.len());
+            leap_seconds.push(leap_second.ok_or_else(invalid)?);
+            steps.push(tai_utc - previous_tai_utc);
+            previous_tai_utc = tai_utc;
+        }
+        Ok(LeapTable::new(leap_seconds, steps, expires))
+    }
+
+    /// Parse a zic-style tzdata `leapseconds` file, e.g. `Leap 2016 Dec 31 23:59:60 + S` lines and an optional
+    /// `Expires` line. Negative leap seconds are rejected, as none have occurred and conversions do not model them.
+    pub fn parse_tzdata_leapseconds(leapseconds: &str) -> Result<LeapTable, GpstError> {
+        let mut leap_seconds = Vec::new();
+        let mut expires = None;
+        for (number, line) in leapseconds.lines().enumerate() {
+            let invalid = || GpstError::Parse(ParseInput::at_line("leapseconds", number + 1));
+            let fields: Vec<_> = line
+                .split('#')
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect();
+            match fields.as_slice() {
+                [] => {}
+                ["Leap", year, month, day, _time, correction, _rolling] => {
+                    if *correction != "+" {
+                        return Err(invalid());
+                    }
+                    let date =
+                        NaiveDate::parse_from_str(&format!("{year} {month} {day}"), "%Y %b %d")
+                            .map_err(|_| invalid())?;
+                    let effective = date
+                        .succ_opt()
+                        .and_then(|date| date.and_hms_opt(0, 0, 0))
+                        .ok_or_else(invalid)?;
+                    if effective.and_utc().timestamp() > GPS_EPOCH / TO_NANO_INT {
+                        let leap_second = inserted_second(effective, leap_seconds.len());
+                        leap_seconds.push(leap_second.ok_or_else(invalid)?);
+                    }
+                }
+                ["Expires", year, month, day, time] => {
+                    let date_time = NaiveDateTime::parse_from_str(
+                        &format!("{year} {month} {day} {time}"),
+                        "%Y %b %d %H:%M:%S",
+                    )
+                    .map_err(|_| invalid())?;
+                    expires = Some(date_time.and_utc());
+                }
+                _ => return Err(invalid()),
+            }
+        }
+        let steps = vec![1; leap_seconds.len()];
+        Ok(LeapTable::new(leap_seconds, steps, expires))
+    }
+
+    /// Load the system tzdata table from `$TZDIR` or `/usr/share/zoneinfo`, preferring `leap-seconds.list` over
+    /// `leapseconds`.
+    pub fn load_system() -> Result<LeapTable, GpstError> {
+        let directory = std::env::var_os("TZDIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo"));
+        LeapTable::load_dir(&directory)
+    }
+
+    /// Load a tzdata table from a directory, preferring `leap-seconds.list` over `leapseconds`.
+    pub fn load_dir(directory: &Path) -> Result<LeapTable, GpstError> {
+        for file_name in FILE_NAMES {
+            let path = directory.join(file_name);
+            let Ok(text) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            return if file_name == FILE_NAMES[0] {
+                LeapTable::parse_leap_seconds_list(&text)
+            } else {
+                LeapTable::parse_tzdata_leapseconds(&text)
+            };
+        }
+        Err(GpstError::LeapTableUnavailable)
+    }
+
+    /// Start of each inserted leap second since GPS Epoch, in seconds on the GPS time scale.
+    pub fn leap_seconds(&self) -> &[i64] {
+        &self.leap_seconds
+    }
+
+    /// Date through which the table is known complete, if the file declares one.
+    pub fn expires(&self) -> Option<DateTime<Utc>> {
+        self.expires
+    }
+
+    /// Check the table before trusting it with conversions: leap seconds must increase, end UTC days, step TAI - UTC
+    /// by exactly one second and agree with the builtin historical table, and the table must declare an expiry date
+    /// after its last leap second. Returns every issue found, empty for a valid table.
+    pub fn validate_leap_table(&self) -> Vec<LeapTableIssue> {
+        let mut issues = Vec::new();
+        for (index, leap_second) in self.leap_seconds.iter().enumerate() {
+            if index > 0 && *leap_second <= self.leap_seconds[index - 1] {
+                issues.push(LeapTableIssue::NotIncreasing { index });
+            }
+            // Shifted back by the leap seconds before it, the inserted second starts at the UTC midnight ending its day.
+            if (leap_second - index as i64).rem_euclid(SECONDS_PER_DAY) != 0 {
+                issues.push(LeapTableIssue::NotAtMidnight { index });
+            }
+        }
+        for (index, step) in self.steps.iter().enumerate() {
+            if *step != 1 {
+                issues.push(LeapTableIssue::Step { index, step: *step });
+            }
+        }
+        let mismatch = self
+            .leap_seconds
+            .iter()
+            .zip(LEAP_SECONDS)
+            .position(|(found, expected)| *found != expected);
+        if let Some(index) = mismatch {
+            issues.push(LeapTableIssue::Historical {
+                index,
+                expected: LEAP_SECONDS[index],
+                found: self.leap_seconds[index],
+            });
+        } else if self.leap_seconds.len() < LEAP_SECONDS.len() {
+            issues.push(LeapTableIssue::MissingHistorical {
+                missing: LEAP_SECONDS.len() - self.leap_seconds.len(),
+            });
+        }
+        match (self.expires, self.leap_nanoseconds_utc.last()) {
+            (None, _) => issues.push(LeapTableIssue::NoExpiry),
+            (Some(expires), Some(last)) if gps_epoch_utc_nanoseconds(&expires) <= *last => {
+                issues.push(LeapTableIssue::ExpiresBeforeLastLeap { expires })
+            }
+            _ => {}
+        }
+        issues
+    }
+
+    fn new(leap_seconds: Vec<i64>, steps: Vec<i64>, expires: Option<DateTime<Utc>>) -> LeapTable {
+        let leap_nanoseconds_utc = leap_seconds
+            .iter()
+            .enumerate()
+            .map(|(index, leap_second)| (leap_second - index as i64) * TO_NANO_INT)
+            .collect();
+        LeapTable {
+            leap_nanoseconds: leap_seconds
+                .iter()
+                .map(|leap_second| leap_second * TO_NANO_INT)
+                .collect(),
+            leap_seconds,
+            leap_nanoseconds_utc,
+            steps,
+            expires,
+        }
+    }
+
+    fn num_leaps_utc(&self, utc_nanoseconds: i64) -> i64 {
+        count_leaps(&self.leap_nanoseconds_utc, utc_nanoseconds, true) as i64 * TO_NANO_INT
+    }
+}
+
+impl TimeConverter for LeapTable {
+    fn to_gpst(&self, date_time: &DateTime<Utc>) -> Result<Gpst, GpstError> {
+        let mut nanoseconds = gps_nanoseconds_with(date_time, LeapHandling::None, |_| 0)?;
+        let utc_nanoseconds = i64::try_from(nanoseconds).unwrap_or(i64::MAX);
+        nanoseconds += self.num_leaps_utc(utc_nanoseconds) as i128;
+        // chrono folds a 23:59:60 reading onto the following second, so move it back into the inserted second.
+        let inserted = date_time.nanosecond() >= TO_NANO_INT as u32
+            && self
+                .leap_nanoseconds_utc
+                .iter()
+                .any(|start| (*start..start + TO_NANO_INT).contains(&utc_nanoseconds));
+        if inserted {
+            nanoseconds -= TO_NANO_INT as i128;
+        }
+        Ok(Gpst::from_converted_nanoseconds(
+            nanoseconds,
+            LeapHandling::Step,
+        ))
+    }
+
+    fn to_utc(&self, gpst: &Gpst) -> Result<DateTime<Utc>, GpstError> {
+        gpst.check_converted_with(LeapHandling::Step)?;
+        from_gps_nanoseconds_with(
+            gpst.wide_nanoseconds_since_epoch(),
+            LeapHandling::Step,
+            |nanoseconds| {
+                count_leaps(&self.leap_nanoseconds, nanoseconds, false) as i64 * TO_NANO_INT
+            },
+        )
+    }
+}
+
+/// UTC nanoseconds since GPS Epoch of a date-time, saturating beyond `i64`.
+fn gps_epoch_utc_nanoseconds(date_time: &DateTime<Utc>) -> i64 {
+    date_time
+        .timestamp()
+        .saturating_mul(TO_NANO_INT)
+        .saturating_sub(GPS_EPOCH)
+}
+
+/// GPS seconds since GPS Epoch of the leap second inserted before the UTC midnight `effective`, given the number of
+/// leap seconds since GPS Epoch before it. `None` past the `i64` nanoseconds the table is kept in (about 2262).
+fn inserted_second(effective: NaiveDateTime, previous: usize) -> Option<i64> {
+    let leap_second = effective.and_utc().timestamp() - GPS_EPOCH / TO_NANO_INT + previous as i64;
+    leap_second.checked_mul(TO_NANO_INT)?;
+    Some(leap_second)
+}
+
+/// The UTC instant of an NTP timestamp. `None` outside of chrono's date range.
+fn from_ntp_seconds(ntp_seconds: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(ntp_seconds.checked_sub(NTP_UNIX_EPOCH)?, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        GpstError, GpstLike, LeapTable, LeapTableIssue, ParseInput, TimeConverter, LEAP_SECONDS,
+    };
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use std::path::Path;
+
+    const LEAPSECONDS: &str = "\
+# Comment
+Leap\t1972\tJun\t30\t23:59:60\t+\tS
+Leap\t1981\tJun\t30\t23:59:60\t+\tS
+Leap\t1982\tJun\t30\t23:59:60\t+\tS
+Expires 2026\tJun\t28\t00:00:00
+";
+
+    #[test]
+    fn leap_seconds_list() {
+        let table =
+            LeapTable::parse_leap_seconds_list(include_str!("../data/leap-seconds.list")).unwrap();
+        assert_eq!(table.leap_seconds(), LEAP_SECONDS);
+        assert_eq!(table, LeapTable::builtin());
+        assert!(LeapTable::parse_leap_seconds_list("2272060800 abc").is_err());
+
+        // A leap second past the table's `i64` nanoseconds, and an expiry overflowing the NTP epoch offset.
+        let list = "# Comment\n12000000000 40";
+        assert_eq!(
+            LeapTable::parse_leap_seconds_list(list),
+            Err(GpstError::Parse(ParseInput::at_line(
+                "leap-seconds.list",
+                2
+            )))
+        );
+        assert_eq!(
+            LeapTable::parse_leap_seconds_list("#@-9223372036854775808"),
+            Err(GpstError::Parse(ParseInput::at_line(
+                "leap-seconds.list",
+                1
+            )))
+        );
+    }
+
+    #[test]
+    fn tzdata_leapseconds() {
+        let table = LeapTable::parse_tzdata_leapseconds(LEAPSECONDS).unwrap();
+        assert_eq!(table.leap_seconds(), &LEAP_SECONDS[..2]);
+        assert_eq!(table.expires(), LeapTable::builtin().expires());
+        let negative = "Leap\t2030\tJun\t30\t23:59:59\t-\tS";
+        assert!(matches!(
+            LeapTable::parse_tzdata_leapseconds(negative),
+            Err(GpstError::Parse(_))
+        ));
+        let late = format!("{LEAPSECONDS}Leap\t2300\tDec\t31\t23:59:60\t+\tS");
+        assert_eq!(
+            LeapTable::parse_tzdata_leapseconds(&late),
+            Err(GpstError::Parse(ParseInput::at_line("leapseconds", 6)))
+        );
+    }
+
+    #[test]
+    fn validate() {
+        let list = include_str!("../data/leap-seconds.list");
+        let table = LeapTable::parse_leap_seconds_list(list).unwrap();
+        assert_eq!(table.validate_leap_table(), []);
+        assert_eq!(LeapTable::builtin().validate_leap_table(), []);
+
+        let table = LeapTable::parse_tzdata_leapseconds(LEAPSECONDS).unwrap();
+        assert_eq!(
+            table.validate_leap_table(),
+            [LeapTableIssue::MissingHistorical {
+                missing: LEAP_SECONDS.len() - 2
+            }]
+        );
+
+        // 1982 before 1981, with no expiry date.
+        let swapped = "\
+Leap\t1982\tJun\t30\t23:59:60\t+\tS
+Leap\t1981\tJun\t30\t23:59:60\t+\tS
+";
+        let issues = LeapTable::parse_tzdata_leapseconds(swapped)
+            .unwrap()
+            .validate_leap_table();
+        assert!(issues.contains(&LeapTableIssue::NotIncreasing { index: 1 }));
+        assert!(issues.contains(&LeapTableIssue::Historical {
+            index: 0,
+            expected: LEAP_SECONDS[0],
+            found: LEAP_SECONDS[1] - 1,
+        }));
+        assert!(issues.contains(&LeapTableIssue::NoExpiry));
+
+        let last = LEAP_SECONDS.len() - 1;
+        let stepped = list.replace("3692217600      37", "3692217600      38");
+        assert_eq!(
+            LeapTable::parse_leap_seconds_list(&stepped)
+                .unwrap()
+                .validate_leap_table(),
+            [LeapTableIssue::Step {
+                index: last,
+                step: 2
+            }]
+        );
+        let late = list.replace("3692217600      37", "3692217601      37");
+        assert_eq!(
+            LeapTable::parse_leap_seconds_list(&late)
+                .unwrap()
+                .validate_leap_table(),
+            [
+                LeapTableIssue::NotAtMidnight { index: last },
+                LeapTableIssue::Historical {
+                    index: last,
+                    expected: LEAP_SECONDS[last],
+                    found: LEAP_SECONDS[last] + 1,
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn converts_with_table() {
+        // A future leap second at the end of 2030, unknown to the builtin table.
+        let text = format!("{LEAPSECONDS}Leap\t2030\tDec\t31\t23:59:60\t+\tS\n");
+        let table = LeapTable::parse_tzdata_leapseconds(&text).unwrap();
+        let before = Utc.with_ymd_and_hms(1982, 6, 30, 12, 0, 0).unwrap();
+        assert_eq!(table.to_gpst(&before).unwrap(), before.gpst(true).unwrap());
+
+        // Only three leap seconds since GPS Epoch in this table, the last one in 2030.
+        let after = Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap();
+        let gpst = table.to_gpst(&after).unwrap();
+        let expected = after.gpst(false).unwrap().week_seconds + 3.0;
+        assert_eq!(gpst.week_seconds, expected);
+        assert_eq!(table.to_utc(&gpst).unwrap(), after);
+        assert!(table.to_utc(&after.gpst(false).unwrap()).is_err());
+        let inserted = NaiveDate::from_ymd_opt(2030, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap()
+            .and_utc();
+        assert_eq!(
+            table.to_gpst(&inserted).unwrap().week_seconds,
+            expected - 0.5
+        );
+    }
+
+    #[test]
+    fn load_dir() {
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        assert_eq!(
+            LeapTable::load_dir(&directory).unwrap(),
+            LeapTable::builtin()
+        );
+        assert_eq!(
+            LeapTable::load_dir(Path::new("/nonexistent")),
+            Err(GpstError::LeapTableUnavailable)
+        );
+    }
+
+    // Depends on the host's tzdata, run with `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn system() {
+        let table = LeapTable::load_system().unwrap();
+        assert!(table.leap_seconds().starts_with(&LEAP_SECONDS));
+    }
+}
@@ -0,0 +1,49 @@
+//! u-blox UBX time of week (iTOW) conversions.
+
+use crate::{Gpst, GpstError};
+
+/// Milliseconds in a GPS week.
+const MILLISECONDS_PER_WEEK: u32 = 604_800_000;
+const NANOSECONDS_PER_MILLISECOND: i64 = 1_000_000;
+
+impl Gpst {
+    /// GPS time of week in integer milliseconds, as reported by u-blox NAV messages. Week seconds are rounded to the
+    /// nanosecond before truncating to the millisecond, so `x.999999999` float noise does not lose a millisecond.
+    pub fn itow_ms(&self) -> u32 {
+        let milliseconds = self.week_nanoseconds() / NANOSECONDS_PER_MILLISECOND;
+        (milliseconds as u32).min(MILLISECONDS_PER_WEEK - 1)
+    }
+
+    /// Build GPST data from a week and a u-blox iTOW in milliseconds.
+    pub fn from_week_itow(week: i64, itow_ms: u32) -> Result<Gpst, GpstError> {
+        if itow_ms >= MILLISECONDS_PER_WEEK {
+            return Err(GpstError::Itow(itow_ms));
+        }
+        // Wide, as week numbers from receivers or corrupted messages can be past `i64` nanoseconds.
+        Ok(Gpst::from_wide_nanoseconds(
+            (week as i128 * MILLISECONDS_PER_WEEK as i128 + itow_ms as i128)
+                * NANOSECONDS_PER_MILLISECOND as i128,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Gpst;
+
+    #[test]
+    fn itow() {
+        let gpst = Gpst::from_week_itow(1307, 480_613_250).unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.25));
+        assert_eq!(gpst.itow_ms(), 480_613_250);
+        assert_eq!(Gpst::normalized(1307, 0.3 * 3.0).itow_ms(), 900);
+        assert!(Gpst::from_week_itow(1307, 604_800_000).is_err());
+
+        assert_eq!(
+            Gpst::from_week_itow(20000, 480_613_250).unwrap(),
+            Gpst::normalized(20000, 480613.25)
+        );
+        let last = Gpst::from_week_itow(i64::MAX, 604_799_999).unwrap();
+        assert_eq!((last.week, last.itow_ms()), (i64::MAX, 604_799_999));
+    }
+}
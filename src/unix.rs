@@ -0,0 +1,141 @@
+//! Conversions between raw Unix timestamps and GPST data that never construct a [`chrono::DateTime`], for
+//! pipelines whose data is already Unix time.
+//!
+//! Arithmetic is in `i128` nanoseconds, so there is no 2262 limit as with `i64` Unix nanoseconds. Unix time repeats
+//! the second before a leap second takes effect, so the inserted second itself is not representable.
+
+use crate::{
+    clamp_to_i64, num_leaps, num_leaps_utc, smeared_leaps_utc, unix_nanoseconds_with, Gpst,
+    GpstError, LeapHandling, TimeInput, GPS_EPOCH, TO_NANO_INT,
+};
+
+/// Convert a Unix timestamp in whole seconds and a nanosecond remainder to GPS Time (GPST), as [`GpstLike::gpst`]
+/// does for the same instant. Optionally, adjust for leap seconds.
+///
+/// Fails on nanoseconds not less than one second and instants before GPS Epoch.
+///
+/// [`GpstLike::gpst`]: crate::GpstLike::gpst
+pub fn unix_to_gpst(
+    seconds: i64,
+    nanoseconds: u32,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<Gpst, GpstError> {
+    if nanoseconds as i64 >= TO_NANO_INT {
+        return Err(GpstError::Nanoseconds(nanoseconds));
+    }
+    let leap_seconds = leap_seconds.into();
+    let unix_nanoseconds = seconds as i128 * TO_NANO_INT as i128 + nanoseconds as i128;
+    let utc_nanoseconds = unix_nanoseconds - GPS_EPOCH as i128;
+    let lookup = clamp_to_i64(utc_nanoseconds);
+    let leap_nanoseconds = match leap_seconds {
+        LeapHandling::None => 0,
+        LeapHandling::Step => num_leaps_utc(lookup),
+        LeapHandling::Smear24h => smeared_leaps_utc(lookup),
+    };
+    let gps_nanoseconds = utc_nanoseconds + leap_nanoseconds as i128;
+    if gps_nanoseconds < 0 {
+        return Err(GpstError::BeforeGPSEpoch(TimeInput::UnixNanoseconds(
+            unix_nanoseconds,
+        )));
+    }
+    Ok(Gpst::from_converted_nanoseconds(
+        gps_nanoseconds,
+        leap_seconds,
+    ))
+}
+
+/// Convert GPS Time (GPST) to a Unix timestamp in whole seconds and a nanosecond remainder, as [`crate::from_gpst`]
+/// does for the same epoch. Optionally, adjust for leap seconds.
+///
+/// Fails if the whole seconds overflow `i64`, e.g. on garbage week numbers, and on GPST data converted from UTC with
+/// a different leap handling.
+pub fn gpst_to_unix(
+    gpst: &Gpst,
+    leap_seconds: impl Into<LeapHandling>,
+) -> Result<(i64, u32), GpstError> {
+    let leap_seconds = leap_seconds.into();
+    gpst.check_converted_with(leap_seconds)?;
+    let nanoseconds = gpst.wide_nanoseconds_since_epoch();
+    let unix_nanoseconds = unix_nanoseconds_with(nanoseconds, leap_seconds, num_leaps);
+    let seconds = i64::try_from(unix_nanoseconds.div_euclid(TO_NANO_INT as i128))
+        .map_err(|_| GpstError::Overflow(TimeInput::GpsNanoseconds(nanoseconds)))?;
+    Ok((
+        seconds,
+        unix_nanoseconds.rem_euclid(TO_NANO_INT as i128) as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, gpst_to_unix, unix_to_gpst, Gpst, GpstError, GpstLike, LeapHandling};
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[test]
+    fn matches_date_time() {
+        let date_time = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        for leap_seconds in [
+            LeapHandling::None,
+            LeapHandling::Step,
+            LeapHandling::Smear24h,
+        ] {
+            for offset in [0, 500_000_001] {
+                let date_time = date_time + chrono::TimeDelta::nanoseconds(offset);
+                let gpst = unix_to_gpst(
+                    date_time.timestamp(),
+                    date_time.timestamp_subsec_nanos(),
+                    leap_seconds,
+                )
+                .unwrap();
+                assert_eq!(gpst, date_time.gpst(leap_seconds).unwrap());
+                let expected = from_gpst(gpst.week, gpst.week_seconds, leap_seconds).unwrap();
+                assert_eq!(
+                    gpst_to_unix(&gpst, leap_seconds).unwrap(),
+                    (expected.timestamp(), expected.timestamp_subsec_nanos())
+                );
+            }
+        }
+        let gpst = unix_to_gpst(1106919000, 0, true).unwrap();
+        assert_eq!((gpst.week, gpst.week_seconds), (1307, 480613.0));
+        assert_eq!(gpst_to_unix(&gpst, true).unwrap(), (1106919000, 0));
+    }
+
+    #[test]
+    fn beyond_2262() {
+        // Week 20000 (2363) is past i64 Unix nanoseconds and chrono's nanosecond timestamps.
+        let gpst = Gpst::new(20000, 0.5).unwrap();
+        let (seconds, nanoseconds) = gpst_to_unix(&gpst, true).unwrap();
+        assert_eq!(nanoseconds, 500000000);
+        assert!(DateTime::from_timestamp(seconds, 0)
+            .unwrap()
+            .timestamp_nanos_opt()
+            .is_none());
+        let decoded = unix_to_gpst(seconds, nanoseconds, true).unwrap();
+        assert_eq!((decoded.week, decoded.week_seconds), (20000, 0.5));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            unix_to_gpst(0, 1_000_000_000, true),
+            Err(GpstError::Nanoseconds(1_000_000_000))
+        );
+        assert!(matches!(
+            unix_to_gpst(0, 0, true),
+            Err(GpstError::BeforeGPSEpoch(_))
+        ));
+        assert!(gpst_to_unix(&Gpst::normalized(i64::MAX, 0.0), true).is_err());
+    }
+
+    #[test]
+    fn mixed_leap_handling() {
+        let gpst = unix_to_gpst(1106919000, 0, false).unwrap();
+        assert_eq!(
+            gpst_to_unix(&gpst, true),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::None,
+                LeapHandling::Step
+            ))
+        );
+        assert_eq!(gpst_to_unix(&gpst, false).unwrap(), (1106919000, 0));
+    }
+}
@@ -0,0 +1,124 @@
+//! UT1 from GPS epochs through a DUT1 (UT1 − UTC) provider, e.g. fed from IERS Bulletin A.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+
+use crate::{Gpst, GpstError, GpstLike, LeapHandling, TO_NANO_FLOAT};
+
+/// A source of DUT1, the difference UT1 − UTC.
+pub trait Ut1Provider {
+    /// UT1 − UTC in seconds at a UTC instant, or `None` outside of the provider's coverage.
+    fn dut1(&self, utc: &DateTime<Utc>) -> Option<f64>;
+}
+
+/// Daily DUT1 values at 0h UTC, as tabulated in IERS Bulletin A, linearly interpolated between days.
+///
+/// Values step by one second across a leap second; interpolation accounts for the step, so the day before a leap
+/// second does not drift by a full second.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Dut1Table {
+    values: Vec<(NaiveDate, f64)>,
+}
+
+impl Dut1Table {
+    /// Build a table from `(date, UT1 − UTC seconds)` pairs, in any order.
+    pub fn new(values: impl IntoIterator<Item = (NaiveDate, f64)>) -> Dut1Table {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.sort_by_key(|(date, _)| *date);
+        Dut1Table { values }
+    }
+}
+
+impl Ut1Provider for Dut1Table {
+    fn dut1(&self, utc: &DateTime<Utc>) -> Option<f64> {
+        let date = utc.date_naive();
+        let index = self.values.partition_point(|(day, _)| *day <= date);
+        let &(start, before) = self.values.get(index.checked_sub(1)?)?;
+        if start == date && *utc == start.and_hms_opt(0, 0, 0)?.and_utc() {
+            return Some(before);
+        }
+        let &(end, after) = self.values.get(index)?;
+        let span = (end - start).num_seconds() as f64;
+        let elapsed = (utc.naive_utc() - start.and_hms_opt(0, 0, 0)?).num_nanoseconds()? as f64
+            / TO_NANO_FLOAT;
+        // A leap second steps DUT1 by a whole second at the end of the interval, after every instant in it.
+        let after = after - (after - before).round();
+        Some(before + (after - before) * elapsed / span)
+    }
+}
+
+impl Gpst {
+    /// The UT1 date-time of this GPS epoch, converting to UTC with the leap second handling recorded in the value
+    /// (stepped if unknown), then applying DUT1 from `provider`.
+    pub fn to_ut1(&self, provider: &impl Ut1Provider) -> Result<NaiveDateTime, GpstError> {
+        let utc = DateTime::<Utc>::try_from(*self)?;
+        let dut1 = provider.dut1(&utc).ok_or(GpstError::Ut1Unavailable(utc))?;
+        Ok(utc.naive_utc() + dut1_delta(dut1))
+    }
+
+    /// GPS Time (GPST) of a UT1 date-time, the inverse of [`Gpst::to_ut1`]. Optionally, adjust for leap seconds.
+    pub fn from_ut1(
+        ut1: NaiveDateTime,
+        provider: &impl Ut1Provider,
+        leap_seconds: impl Into<LeapHandling>,
+    ) -> Result<Gpst, GpstError> {
+        // DUT1 changes by milliseconds per day, so DUT1 at UT1 read as UTC is accurate to well below a nanosecond,
+        // and one refinement at the resulting UTC settles it.
+        let mut utc = ut1.and_utc();
+        for _ in 0..2 {
+            let dut1 = provider.dut1(&utc).ok_or(GpstError::Ut1Unavailable(utc))?;
+            utc = (ut1 - dut1_delta(dut1)).and_utc();
+        }
+        utc.gpst(leap_seconds)
+    }
+}
+
+fn dut1_delta(dut1: f64) -> TimeDelta {
+    TimeDelta::nanoseconds((dut1 * TO_NANO_FLOAT).round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Dut1Table, Gpst, GpstError, GpstLike, Ut1Provider};
+    use chrono::{NaiveDate, TimeDelta, TimeZone, Utc};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn table() {
+        let table = Dut1Table::new([
+            (date(2017, 1, 1), 0.5920),
+            (date(2016, 12, 31), -0.4072),
+            (date(2016, 12, 30), -0.4066),
+        ]);
+        let noon = Utc.with_ymd_and_hms(2016, 12, 30, 12, 0, 0).unwrap();
+        assert!((table.dut1(&noon).unwrap() - -0.4069).abs() < 1e-12);
+        // Across the leap second, the day before interpolates to the pre-leap value.
+        let noon = Utc.with_ymd_and_hms(2016, 12, 31, 12, 0, 0).unwrap();
+        assert!((table.dut1(&noon).unwrap() - -0.4076).abs() < 1e-12);
+        let midnight = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(table.dut1(&midnight), Some(0.5920));
+        assert_eq!(table.dut1(&(midnight + TimeDelta::seconds(1))), None);
+        assert_eq!(
+            table.dut1(&Utc.with_ymd_and_hms(2016, 12, 29, 0, 0, 0).unwrap()),
+            None
+        );
+    }
+
+    #[test]
+    fn to_ut1() {
+        let table = Dut1Table::new([(date(2005, 1, 28), -0.5), (date(2005, 1, 29), -0.5)]);
+        let utc = Utc.with_ymd_and_hms(2005, 1, 28, 13, 30, 0).unwrap();
+        let gpst = utc.gpst(true).unwrap();
+        let ut1 = gpst.to_ut1(&table).unwrap();
+        assert_eq!(ut1, utc.naive_utc() - TimeDelta::milliseconds(500));
+        assert_eq!(Gpst::from_ut1(ut1, &table, true).unwrap(), gpst);
+
+        let late = Utc.with_ymd_and_hms(2005, 2, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            late.gpst(true).unwrap().to_ut1(&table),
+            Err(GpstError::Ut1Unavailable(late))
+        );
+    }
+}
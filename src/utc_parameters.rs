@@ -0,0 +1,230 @@
+//! Broadcast GPS–UTC correction parameters, for the full IS-GPS-200 20.3.3.5.2.4 conversion.
+//!
+//! Beyond the integer leap seconds of the builtin table, the navigation message broadcasts a first-order polynomial
+//! (A0, A1, referenced to tot and WNt) for the sub-microsecond offset between GPS Time and UTC(USNO), and announces
+//! upcoming leap seconds (ΔtLSF at the end of day DN of week WNLSF).
+
+use chrono::{DateTime, Timelike, Utc};
+
+use crate::{
+    from_wide_gps_nanoseconds, Gpst, GpstError, LeapHandling, TimeInput, GPS_EPOCH, TO_NANO_FLOAT,
+    TO_NANO_INT,
+};
+
+const SECONDS_PER_DAY: i64 = 86400;
+const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+
+/// GPS–UTC parameters from subframe 4 page 18 (LNAV) or message type 33 (CNAV).
+///
+/// Week numbers are full weeks; resolve the broadcast 8-bit WNt and WNLSF with [`crate::resolve_truncated_week`]
+/// and [`crate::WeekWidth::Almanac8`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct UtcParameters {
+    /// Bias (s).
+    pub a0: f64,
+    /// Drift (s/s).
+    pub a1: f64,
+    /// Reference time of week of the polynomial (s).
+    pub tot: f64,
+    /// Reference week of the polynomial.
+    pub wnt: i64,
+    /// Current leap seconds (ΔtLS).
+    pub delta_t_ls: i64,
+    /// Leap seconds after the announced event (ΔtLSF), equal to ΔtLS if none is scheduled.
+    pub delta_t_lsf: i64,
+    /// Week of the leap second event (WNLSF).
+    pub wn_lsf: i64,
+    /// Day of week at the end of which the leap second takes effect (DN), 1 (Sunday) through 7.
+    pub dn: u8,
+}
+
+impl UtcParameters {
+    /// The polynomial part of the GPS–UTC offset at a GPS epoch, `A0 + A1 (tE − tot + 604800 (WN − WNt))`, in
+    /// seconds.
+    pub fn polynomial(&self, gpst: &Gpst) -> f64 {
+        let elapsed =
+            gpst.week_seconds - self.tot + ((gpst.week - self.wnt) * SECONDS_PER_WEEK) as f64;
+        self.a0 + self.a1 * elapsed
+    }
+
+    /// Convert a GPS epoch to UTC with the broadcast parameters, following the three cases of IS-GPS-200
+    /// 20.3.3.5.2.4: ΔtLS before the leap second event, ΔtLSF after it, and `23:59:60` (a chrono leap second, with
+    /// nanoseconds past one second) during an inserted second.
+    ///
+    /// `ΔtLSF − ΔtLS` is ±1 in practice; larger steps are not representable and clamp at `23:59:60.999999999`.
+    /// The epoch must be true GPS time, so one converted with other than [`LeapHandling::Step`] fails with
+    /// [`GpstError::MixedLeapHandling`].
+    pub fn to_utc(&self, gpst: &Gpst) -> Result<DateTime<Utc>, GpstError> {
+        self.validate()?;
+        gpst.check_converted_with(LeapHandling::Step)?;
+        let overflow = || GpstError::Overflow(TimeInput::WeekSeconds(gpst.week, gpst.week_seconds));
+        let polynomial = (self.polynomial(gpst) * TO_NANO_FLOAT).round() as i128;
+        // UTC, counted in nanoseconds of the GPS Epoch calendar with the current ΔtLS.
+        let before = gpst
+            .checked_nanoseconds_since_epoch()
+            .ok_or_else(overflow)?
+            - self.delta_t_ls as i128 * TO_NANO_INT as i128
+            - polynomial;
+        let midnight = self.event_midnight();
+        let step = (self.delta_t_lsf - self.delta_t_ls) as i128 * TO_NANO_INT as i128;
+        if before < midnight {
+            return from_wide_gps_nanoseconds(before, false);
+        }
+        if before >= midnight + step {
+            return from_wide_gps_nanoseconds(before - step, false);
+        }
+        let inserted = (before - midnight).min(TO_NANO_INT as i128 - 1) as u32;
+        let last_second = from_wide_gps_nanoseconds(midnight - TO_NANO_INT as i128, false)?;
+        last_second
+            .with_nanosecond(TO_NANO_INT as u32 + inserted)
+            .ok_or_else(overflow)
+    }
+
+    /// Convert UTC to a GPS epoch with the broadcast parameters, the inverse of [`UtcParameters::to_utc`]. A chrono
+    /// leap second at the event converts into the inserted second. The result is true GPS time, recorded as
+    /// [`LeapHandling::Step`].
+    pub fn to_gpst(&self, date_time: &DateTime<Utc>) -> Result<Gpst, GpstError> {
+        self.validate()?;
+        let utc = date_time.timestamp() as i128 * TO_NANO_INT as i128
+            + date_time.timestamp_subsec_nanos() as i128
+            - GPS_EPOCH as i128;
+        let midnight = self.event_midnight();
+        let leap_second = date_time.timestamp_subsec_nanos() >= TO_NANO_INT as u32;
+        let before = if leap_second || utc < midnight {
+            utc
+        } else {
+            utc + (self.delta_t_lsf - self.delta_t_ls) as i128 * TO_NANO_INT as i128
+        };
+        let coarse = before + self.delta_t_ls as i128 * TO_NANO_INT as i128;
+        // A1 is tiny, so evaluating the polynomial at the coarse epoch is exact to well below a nanosecond.
+        let polynomial = self.polynomial(&Gpst::from_wide_nanoseconds(coarse));
+        let nanoseconds = coarse + (polynomial * TO_NANO_FLOAT).round() as i128;
+        if nanoseconds < 0 {
+            return Err(GpstError::BeforeGPSEpoch(TimeInput::Utc(*date_time)));
+        }
+        Ok(Gpst::from_wide_nanoseconds(nanoseconds).with_leap_handling(LeapHandling::Step))
+    }
+
+    /// UTC midnight ending day DN of week WNLSF, in nanoseconds of the GPS Epoch calendar.
+    fn event_midnight(&self) -> i128 {
+        (self.wn_lsf as i128 * SECONDS_PER_WEEK as i128 + self.dn as i128 * SECONDS_PER_DAY as i128)
+            * TO_NANO_INT as i128
+    }
+
+    fn validate(&self) -> Result<(), GpstError> {
+        if !(1..=7).contains(&self.dn) {
+            return Err(GpstError::LeapDayNumber(self.dn));
+        }
+        if !self.a0.is_finite() {
+            return Err(GpstError::NonFiniteInput(self.a0));
+        }
+        if !self.a1.is_finite() {
+            return Err(GpstError::NonFiniteInput(self.a1));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{from_gpst, Gpst, GpstError, GpstLike, LeapHandling, UtcParameters};
+    use chrono::{Datelike, NaiveDate, TimeDelta, TimeZone, Timelike, Utc};
+
+    /// Parameters announcing the leap second at the end of 2016-12-31, a Saturday in week 1929.
+    const LEAP_2016: UtcParameters = UtcParameters {
+        a0: 0.0,
+        a1: 0.0,
+        tot: 0.0,
+        wnt: 1929,
+        delta_t_ls: 17,
+        delta_t_lsf: 18,
+        wn_lsf: 1929,
+        dn: 7,
+    };
+
+    #[test]
+    fn matches_leap_table() {
+        for seconds in [-43200.0, -1.0, 1.0, 18.0, 43200.0] {
+            let gpst = Gpst::normalized(1930, 17.0 + seconds);
+            assert_eq!(
+                LEAP_2016.to_utc(&gpst).unwrap(),
+                from_gpst(gpst.week, gpst.week_seconds, true).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn inserted_second() {
+        let gpst = Gpst::normalized(1930, 17.25);
+        let utc = LEAP_2016.to_utc(&gpst).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_250_000_000)
+            .unwrap()
+            .and_utc();
+        assert_eq!(utc, expected);
+        assert_eq!(utc.nanosecond(), 1_250_000_000);
+        assert_eq!(
+            LEAP_2016.to_gpst(&utc).unwrap(),
+            gpst.with_leap_handling(true)
+        );
+        let after = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            LEAP_2016.to_gpst(&after).unwrap(),
+            after.gpst(true).unwrap()
+        );
+    }
+
+    #[test]
+    fn polynomial() {
+        let parameters = UtcParameters {
+            a0: 1e-8,
+            a1: 1e-14,
+            tot: 1000.0,
+            ..LEAP_2016
+        };
+        let gpst = Gpst::normalized(1929, 101000.0);
+        assert!((parameters.polynomial(&gpst) - (1e-8 + 1e-9)).abs() < 1e-18);
+        let utc = parameters.to_utc(&gpst).unwrap();
+        let stepped = from_gpst(gpst.week, gpst.week_seconds, true).unwrap();
+        assert_eq!(stepped - utc, TimeDelta::nanoseconds(11));
+        assert_eq!(
+            parameters.to_gpst(&utc).unwrap(),
+            gpst.with_leap_handling(true)
+        );
+
+        let invalid = UtcParameters { dn: 0, ..LEAP_2016 };
+        assert_eq!(invalid.to_utc(&gpst), Err(GpstError::LeapDayNumber(0)));
+    }
+
+    #[test]
+    fn mixed_leap_handling() {
+        let gpst = Gpst::normalized(1930, 17.25);
+        assert!(LEAP_2016.to_utc(&gpst.with_leap_handling(true)).is_ok());
+        assert_eq!(
+            LEAP_2016.to_utc(&gpst.with_leap_handling(false)),
+            Err(GpstError::MixedLeapHandling(
+                LeapHandling::None,
+                LeapHandling::Step
+            ))
+        );
+    }
+
+    #[test]
+    fn beyond_2272() {
+        let parameters = UtcParameters {
+            wnt: 16696,
+            wn_lsf: 16696,
+            delta_t_ls: 18,
+            delta_t_lsf: 18,
+            ..LEAP_2016
+        };
+        let gpst = Gpst::normalized(16696, 45.5);
+        let utc = parameters.to_utc(&gpst).unwrap();
+        assert_eq!(utc.year(), 2299);
+        assert_eq!(
+            parameters.to_gpst(&utc).unwrap(),
+            gpst.with_leap_handling(true)
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Proprietary receiver time tags.
+//!
+//! u-blox iTOW is covered by [`Gpst::itow_ms`] and [`Gpst::from_week_itow`].
+
+use crate::{Gpst, GpstError, TimeInput, TO_NANO_INT};
+
+/// Septentrio SBF block time stamps: continuous week number (WNc) and time of week in milliseconds.
+pub mod septentrio {
+    use super::*;
+
+    /// SBF "do not use" value of the WNc field.
+    pub const WNC_DO_NOT_USE: u16 = u16::MAX;
+    /// SBF "do not use" value of the TOW field.
+    pub const TOW_DO_NOT_USE: u32 = u32::MAX;
+
+    /// Decode an SBF WNc and TOW (ms).
+    pub fn decode(wnc: u16, tow_ms: u32) -> Result<Gpst, GpstError> {
+        if wnc == WNC_DO_NOT_USE || tow_ms == TOW_DO_NOT_USE {
+            return Err(GpstError::TimeTag {
+                vendor: "Septentrio",
+                input: TimeInput::WeekSeconds(wnc.into(), f64::from(tow_ms) / 1e3),
+            });
+        }
+        Gpst::from_week_itow(wnc as i64, tow_ms)
+    }
+
+    /// Encode as an SBF WNc and TOW (ms). `None` if the week does not fit the WNc field.
+    pub fn encode(gpst: &Gpst) -> Option<(u16, u32)> {
+        let wnc = u16::try_from(gpst.week)
+            .ok()
+            .filter(|wnc| *wnc != WNC_DO_NOT_USE)?;
+        Some((wnc, gpst.itow_ms()))
+    }
+}
+
+/// NovAtel OEM log headers: GPS reference week and milliseconds into the week.
+pub mod novatel {
+    use super::*;
+
+    /// Decode a NovAtel header week and milliseconds.
+    pub fn decode(week: u16, milliseconds: u32) -> Result<Gpst, GpstError> {
+        Gpst::from_week_itow(week as i64, milliseconds)
+    }
+
+    /// Encode as a NovAtel header week and milliseconds. `None` if the week does not fit the week field.
+    pub fn encode(gpst: &Gpst) -> Option<(u16, u32)> {
+        Some((u16::try_from(gpst.week).ok()?, gpst.itow_ms()))
+    }
+}
+
+/// Trimble GPS seconds: a single count of seconds since GPS Epoch.
+pub mod trimble {
+    use super::*;
+
+    /// Decode Trimble GPS seconds.
+    pub fn decode(gps_seconds: f64) -> Result<Gpst, GpstError> {
+        if !gps_seconds.is_finite() || gps_seconds < 0.0 {
+            return Err(GpstError::TimeTag {
+                vendor: "Trimble",
+                input: TimeInput::GpsSeconds(gps_seconds),
+            });
+        }
+        Ok(Gpst::normalized(0, gps_seconds))
+    }
+
+    /// Encode as Trimble GPS seconds, from the week number and week seconds, since the `seconds` field of a
+    /// conversion from UTC is truncated to whole seconds.
+    pub fn encode(gpst: &Gpst) -> f64 {
+        gpst.total_seconds() as f64 + gpst.subsec_nanos() as f64 / TO_NANO_INT as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Timelike, Utc};
+
+    use crate::vendors::{novatel, septentrio, trimble};
+    use crate::{Gpst, GpstLike};
+
+    #[test]
+    fn septentrio() {
+        let gpst = septentrio::decode(2280, 345_600_500).unwrap();
+        assert_eq!(gpst, Gpst::normalized(2280, 345600.5));
+        assert_eq!(septentrio::encode(&gpst), Some((2280, 345_600_500)));
+        assert!(septentrio::decode(septentrio::WNC_DO_NOT_USE, 0).is_err());
+        assert!(septentrio::decode(2280, septentrio::TOW_DO_NOT_USE).is_err());
+    }
+
+    #[test]
+    fn novatel() {
+        let gpst = novatel::decode(1307, 480_613_000).unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.0));
+        assert_eq!(novatel::encode(&gpst), Some((1307, 480_613_000)));
+        assert!(novatel::decode(1307, 604_800_000).is_err());
+    }
+
+    #[test]
+    fn trimble() {
+        let gpst = trimble::decode(790954213.5).unwrap();
+        assert_eq!(gpst, Gpst::normalized(1307, 480613.5));
+        assert_eq!(trimble::encode(&gpst), 790954213.5);
+        assert!(trimble::decode(f64::NAN).is_err());
+        let gpst = Utc
+            .with_ymd_and_hms(2005, 1, 28, 13, 30, 0)
+            .unwrap()
+            .with_nanosecond(500_000_000)
+            .unwrap()
+            .gpst(true)
+            .unwrap();
+        assert_eq!(trimble::encode(&gpst), 790954213.5);
+    }
+}
@@ -0,0 +1,121 @@
+//! WebAssembly bindings, enabled with the `wasm` feature, so browser tooling runs the same conversions as Rust. Build
+//! with [wasm-pack](https://rustwasm.github.io/wasm-pack/) (`wasm-pack build --features wasm`), then
+//!
+//! ```js
+//! const { week, tow } = gpstFromDate(new Date("2005-01-28T13:30:00Z"), true); // 1307, 480613
+//! gpstToDate(week, tow, true); // 2005-01-28T13:30:00.000Z
+//! ```
+//!
+//! Instants cross the boundary as JS `Date`s or Unix epoch milliseconds, which may be fractional.
+
+use chrono::{DateTime, Utc};
+use wasm_bindgen::prelude::*;
+
+use crate::{from_gpst, GpstError, GpstLike, TimeInput, TO_NANO_FLOAT};
+
+/// GPS week and time of week, as a JS object with `week` and `tow` properties.
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WeekTow {
+    /// Weeks since GPS Epoch.
+    pub week: i32,
+    /// Seconds of the week.
+    pub tow: f64,
+}
+
+/// Convert Unix epoch milliseconds (UTC) to GPS week and time of week. Optionally, adjust for leap seconds.
+#[wasm_bindgen(js_name = gpstFromUnixMillis)]
+pub fn gpst_from_unix_millis(millis: f64, leap: bool) -> Result<WeekTow, JsError> {
+    week_tow(millis, leap).map_err(js_error)
+}
+
+/// Convert GPS week and time of week to Unix epoch milliseconds (UTC). Optionally, adjust for leap seconds.
+#[wasm_bindgen(js_name = gpstToUnixMillis)]
+pub fn gpst_to_unix_millis(week: i32, tow: f64, leap: bool) -> Result<f64, JsError> {
+    unix_millis(week, tow, leap).map_err(js_error)
+}
+
+/// Convert a JS `Date` to GPS week and time of week. Optionally, adjust for leap seconds.
+#[wasm_bindgen(js_name = gpstFromDate)]
+pub fn gpst_from_date(date: &js_sys::Date, leap: bool) -> Result<WeekTow, JsError> {
+    gpst_from_unix_millis(date.get_time(), leap)
+}
+
+/// Convert GPS week and time of week to a JS `Date`, which truncates to milliseconds. Optionally, adjust for leap
+/// seconds.
+#[wasm_bindgen(js_name = gpstToDate)]
+pub fn gpst_to_date(week: i32, tow: f64, leap: bool) -> Result<js_sys::Date, JsError> {
+    let millis = gpst_to_unix_millis(week, tow, leap)?;
+    Ok(js_sys::Date::new(&JsValue::from_f64(millis)))
+}
+
+fn js_error(error: GpstError) -> JsError {
+    JsError::new(&error.to_string())
+}
+
+fn week_tow(millis: f64, leap: bool) -> Result<WeekTow, GpstError> {
+    if !millis.is_finite() {
+        return Err(GpstError::NonFiniteInput(millis));
+    }
+    // Split before scaling, as `i64` nanoseconds end in 2262 while chrono and JS dates go further.
+    let mut seconds = (millis / 1e3).floor();
+    let mut nanoseconds = ((millis - seconds * 1e3) * 1e6).round();
+    if nanoseconds >= TO_NANO_FLOAT {
+        seconds += 1.0;
+        nanoseconds = 0.0;
+    }
+    // `as` saturates, and saturated seconds are far outside of chrono's range.
+    let date_time = DateTime::<Utc>::from_timestamp(seconds as i64, nanoseconds as u32).ok_or(
+        GpstError::OutOfRange(TimeInput::UnixNanoseconds((millis * 1e6) as i128)),
+    )?;
+    let gpst = date_time.gpst(leap)?;
+    let week =
+        i32::try_from(gpst.week).map_err(|_| GpstError::OutOfRange(TimeInput::Utc(date_time)))?;
+    Ok(WeekTow {
+        week,
+        tow: gpst.week_seconds,
+    })
+}
+
+fn unix_millis(week: i32, tow: f64, leap: bool) -> Result<f64, GpstError> {
+    let date_time = from_gpst(week.into(), tow, leap)?;
+    Ok(date_time.timestamp() as f64 * 1e3
+        + date_time.timestamp_subsec_nanos() as f64 / TO_NANO_FLOAT * 1e3)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wasm::{unix_millis, week_tow, WeekTow};
+
+    // JsError and js_sys::Date only exist inside a JS host, so native tests cover the conversions they wrap.
+    #[test]
+    fn conversions() {
+        // 2005-01-28T13:30:00.250Z
+        let millis = 1106919000250.0;
+        assert_eq!(
+            week_tow(millis, true).unwrap(),
+            WeekTow {
+                week: 1307,
+                tow: 480613.25
+            }
+        );
+        assert_eq!(week_tow(millis, false).unwrap().tow, 480600.25);
+        assert_eq!(unix_millis(1307, 480613.25, true).unwrap(), millis);
+        assert!(week_tow(f64::NAN, true).is_err());
+        assert!(week_tow(0.0, true).is_err());
+        assert!(unix_millis(1307, 604800.0, true).is_err());
+
+        // Past 2262, the end of `i64` nanoseconds: 2300-01-01T00:00:00.500Z.
+        let late = 10413792000500.0;
+        assert_eq!(
+            week_tow(late, true).unwrap(),
+            WeekTow {
+                week: 16696,
+                tow: 86418.5
+            }
+        );
+        assert_eq!(unix_millis(16696, 86418.5, true).unwrap(), late);
+        assert!(week_tow(-0.25, false).is_err());
+        assert!(week_tow(1e300, true).is_err());
+    }
+}